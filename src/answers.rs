@@ -0,0 +1,164 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use crate::error::{ParseError, column_of, parse_number};
+use crate::solver::{Answer, Part};
+
+/// Known-good answers read from a small subset of TOML, for example
+/// ```toml
+/// # Comments and blank lines are ignored
+/// [day1]
+/// part1 = 24000
+/// part2 = 45000
+///
+/// [day5]
+/// part1 = "CMZ"
+///
+/// [day10]
+/// part2 = """
+/// ##..##..
+/// ###...##
+/// """
+/// ```
+/// Integers become [`Answer::Integer`], strings spanning several lines become [`Answer::Grid`]
+/// and any other string becomes [`Answer::Text`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Answers {
+    expected: BTreeMap<(u8, u8), Answer>
+}
+
+impl Answers {
+    pub fn get(&self, day: u8, part: Part) -> Option<&Answer> {
+        self.expected.get(&(day, part.number()))
+    }
+
+    /// Days with at least one recorded answer, in ascending order
+    pub fn days(&self) -> Vec<u8> {
+        let mut days = self.expected.keys().map(|&(day, _)| day).collect::<Vec<u8>>();
+        days.dedup();
+        days
+    }
+
+    pub fn insert(&mut self, day: u8, part: Part, answer: Answer) {
+        self.expected.insert((day, part.number()), answer);
+    }
+
+    pub fn check(&self, day: u8, part: Part, actual: &Answer) -> Verdict {
+        match self.get(day, part) {
+            None => Verdict::Unrecorded,
+            Some(expected) if expected == actual => Verdict::Pass,
+            Some(expected) => Verdict::Fail { expected: expected.clone() }
+        }
+    }
+}
+
+/// Outcome of checking a single part against its recorded answer
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: Answer },
+    Unrecorded
+}
+
+/// Parses the contents of a basic string, i.e. the text between the quotes, handling escapes
+fn unescape(line: &str, text: &str) -> Result<String, ParseError> {
+    let mut result = String::new();
+    let mut chars = text.char_indices();
+    while let Some((index, c)) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some((_, 'n')) => result.push('\n'),
+            Some((_, 't')) => result.push('\t'),
+            Some((_, '"')) => result.push('"'),
+            Some((_, '\\')) => result.push('\\'),
+            _ => return Err(ParseError::unexpected(1, column_of(line, &text[index..]), &text[index..], "one of \\n, \\t, \\\" or \\\\"))
+        }
+    }
+
+    Ok(result)
+}
+
+impl FromStr for Answers {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = Answers::default();
+        let mut day = None;
+        let mut lines = s.lines().enumerate();
+        while let Some((index, line)) = lines.next() {
+            let trimmed = line.trim();
+            let at = |e: ParseError| e.offset_lines(index);
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+            if let Some(table) = trimmed.strip_prefix('[') {
+                let number = table.strip_suffix(']').and_then(|table| table.strip_prefix("day"))
+                    .ok_or_else(|| at(ParseError::unexpected(1, column_of(line, trimmed), trimmed, "a table such as [day1]")))?;
+                day = Some(parse_number::<u8>(line, number).map_err(at)?);
+                continue;
+            }
+
+            let (key, value) = trimmed.split_once('=')
+                .ok_or_else(|| at(ParseError::missing(1, line.chars().count() + 1, "'=' between the part and its answer")))?;
+            let (key, value) = (key.trim(), value.trim());
+            let part = key.strip_prefix("part")
+                .and_then(|n| n.parse::<u8>().ok())
+                .and_then(Part::from_number)
+                .ok_or_else(|| at(ParseError::unexpected(1, column_of(line, key), key, "either part1 or part2")))?;
+            let day = day.ok_or_else(|| at(ParseError::missing(1, 1, "a [dayN] table before the first answer")))?;
+
+            let answer = if value == "\"\"\"" {
+                // Multi-line strings start on the line after the opening quotes and end at the closing quotes
+                let mut rows = Vec::new();
+                loop {
+                    match lines.next() {
+                        Some((_, row)) if row.trim() == "\"\"\"" => break,
+                        Some((_, row)) => rows.push(row.trim_end().to_string()),
+                        None => return Err(ParseError::missing(s.lines().count(), 1, "closing '\"\"\"'"))
+                    }
+                }
+                Answer::Grid(rows)
+            } else if let Some(text) = value.strip_prefix('"') {
+                let text = text.strip_suffix('"')
+                    .ok_or_else(|| at(ParseError::missing(1, line.chars().count() + 1, "closing '\"'")))?;
+                let text = unescape(line, text).map_err(at)?;
+                if text.contains('\n') { Answer::grid(&text) } else { Answer::Text(text) }
+            } else {
+                Answer::Integer(parse_number(line, value).map_err(at)?)
+            };
+            answers.insert(day, part, answer);
+        }
+
+        Ok(answers)
+    }
+}
+
+#[test]
+fn answers_parse_test() {
+    let answers = "# Known answers
+[day1]
+part1 = 24000
+part2 = 45000
+
+[day5]
+part1 = \"CMZ\"
+
+[day10]
+part2 = \"\"\"
+##..
+#..#
+\"\"\"
+".parse::<Answers>().unwrap();
+    assert_eq!(vec![1, 5, 10], answers.days());
+    assert_eq!(Some(&Answer::Integer(45000)), answers.get(1, Part::Two));
+    assert_eq!(Some(&Answer::Text("CMZ".to_string())), answers.get(5, Part::One));
+    assert_eq!(None, answers.get(5, Part::Two));
+    assert_eq!(Verdict::Pass, answers.check(10, Part::Two, &Answer::grid("##..\n#..#\n")));
+    assert_eq!(Verdict::Fail { expected: Answer::Integer(24000) }, answers.check(1, Part::One, &Answer::Integer(24001)));
+    assert_eq!(Verdict::Unrecorded, answers.check(2, Part::One, &Answer::Integer(15)));
+
+    assert_eq!(Err(ParseError::unexpected(2, 1, "part3", "either part1 or part2")), "[day1]\npart3 = 1".parse::<Answers>());
+    assert_eq!(Err(ParseError::invalid_number(2, 9, "24x")), "[day1]\npart1 = 24x".parse::<Answers>());
+}
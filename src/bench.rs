@@ -0,0 +1,118 @@
+use std::time::Duration;
+use crate::error::ParseError;
+use crate::json::Json;
+use crate::solver::{Part, Solution};
+
+/// Summary of the times measured over several runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration
+}
+
+impl Stats {
+    /// Returns `None` if there are no times
+    pub fn from_times(times: &[Duration]) -> Option<Self> {
+        let mut times = times.to_vec();
+        times.sort();
+        let middle = times.len() / 2;
+        let median = match times.len() {
+            0 => return None,
+            n if n % 2 == 0 => (times[middle - 1] + times[middle]) / 2,
+            _ => times[middle]
+        };
+
+        Some(Stats { min: times[0], median, max: times[times.len() - 1] })
+    }
+
+    fn json_fields(self) -> [(&'static str, Json); 3] {
+        [
+            ("min_ms", Json::from(as_millis(self.min))),
+            ("median_ms", Json::from(as_millis(self.median))),
+            ("max_ms", Json::from(as_millis(self.max)))
+        ]
+    }
+}
+
+pub fn as_millis(duration: Duration) -> f64 {
+    duration.as_nanos() as f64 / 1_000_000f64
+}
+
+/// Timings of a day, with parsing measured separately from each part
+#[derive(Debug, Clone)]
+pub struct BenchReport {
+    pub day: u8,
+    pub iterations: usize,
+    pub parse: Stats,
+    pub parts: Vec<(Part, Stats)>
+}
+
+impl BenchReport {
+    pub fn to_json(&self) -> Json {
+        Json::object([
+            ("day", Json::from(self.day)),
+            ("iterations", Json::from(self.iterations)),
+            ("parse", Json::object(self.parse.json_fields())),
+            ("parts", Json::Array(self.parts.iter()
+                .map(|(part, stats)| Json::object(std::iter::once(("part", Json::from(part.number()))).chain(stats.json_fields())))
+                .collect()))
+        ])
+    }
+
+    /// Rows of `day,stage,iterations,min_ms,median_ms,max_ms` where stage is `parse` or the part number
+    pub fn to_csv_rows(&self) -> Vec<String> {
+        let row = |stage: &str, stats: &Stats| format!(
+            "{},{},{},{},{},{}",
+            self.day, stage, self.iterations, as_millis(stats.min), as_millis(stats.median), as_millis(stats.max)
+        );
+        std::iter::once(row("parse", &self.parse))
+            .chain(self.parts.iter().map(|(part, stats)| row(&part.number().to_string(), stats)))
+            .collect()
+    }
+}
+
+pub const CSV_HEADER: &str = "day,stage,iterations,min_ms,median_ms,max_ms";
+
+/// Runs `solution` on `input` `iterations` times, parsing the input again on every run
+///
+/// # Panics
+/// Panics if `iterations` is 0
+pub fn bench(solution: &dyn Solution, input: &str, parts: &[Part], iterations: usize) -> Result<BenchReport, ParseError> {
+    assert!(iterations > 0, "should run at least once");
+    let mut parse_times = Vec::new();
+    let mut part_times = vec![Vec::new(); parts.len()];
+    for _ in 0..iterations {
+        let report = solution.run(input, parts)?;
+        parse_times.push(report.parse_time);
+        for (times, part) in part_times.iter_mut().zip(report.parts) {
+            times.push(part.elapsed);
+        }
+    }
+
+    Ok(BenchReport {
+        day: solution.day(),
+        iterations,
+        parse: Stats::from_times(&parse_times).expect("there should be at least one run"),
+        parts: parts.iter()
+            .zip(part_times)
+            .map(|(&part, times)| (part, Stats::from_times(&times).expect("there should be at least one run")))
+            .collect()
+    })
+}
+
+#[test]
+fn bench_stats_test() {
+    let times = [5, 1, 3, 2].map(Duration::from_millis);
+    let stats = Stats::from_times(&times).unwrap();
+    assert_eq!(Duration::from_millis(1), stats.min);
+    assert_eq!(Duration::from_micros(2500), stats.median);
+    assert_eq!(Duration::from_millis(5), stats.max);
+    assert_eq!(Duration::from_millis(2), Stats::from_times(&times[1..]).unwrap().median);
+    assert_eq!(None, Stats::from_times(&[]));
+
+    let report = bench(&crate::days::day1::Day1, crate::days::day1::EXAMPLE, &[Part::Two], 3).unwrap();
+    assert_eq!(1, report.parts.len());
+    assert_eq!(2, report.to_csv_rows().len());
+    assert!(report.to_csv_rows()[1].starts_with("1,2,3,"));
+}
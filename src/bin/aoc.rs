@@ -0,0 +1,241 @@
+use std::process::ExitCode;
+use std::time::Duration;
+use advent_of_code2022::answers::{Answers, Verdict};
+use advent_of_code2022::bench::{self, BenchReport, Stats};
+use advent_of_code2022::days;
+use advent_of_code2022::inputs::{InputCache, Source};
+use advent_of_code2022::json::Json;
+use advent_of_code2022::solver::{Answer, Part, Report, Solution};
+
+const USAGE: &str = "usage: aoc <command> [day] [-o|--option value]
+Runs the Advent of Code 2022 solutions registered in the library.
+
+Commands
+ run [day]                  run the given day, or every registered day if none is given
+ bench [day]                run the given day, or every registered day, several times and report min/median/max times
+ verify [day]               run the given day, or every day with a recorded answer, and check the answers against the answers file
+ list                       list the registered days
+
+Options
+ -p | --part                1 or 2; only run the given part
+ -i | --input               path of the puzzle input, or - to read it from stdin; default: <input dir>/day<n>
+ -a | --answers             verify only; path of the recorded answers; default: answers.toml
+ -n | --iterations          bench only; number of times each day is run; default: 10
+ -f | --format              text, json or csv (bench only); default: text
+                            run prints one json record per part and line, e.g. {\"day\":1,\"part\":1,\"answer\":...,\"type\":\"integer\",\"elapsed_ms\":...}
+ --help                     view this message
+
+Environment
+ AOC_INPUT_DIR              directory containing the puzzle inputs; default: input";
+
+#[derive(Clone, Copy, PartialEq)]
+enum Format {
+    Text,
+    Json,
+    Csv
+}
+
+struct Options {
+    day: Option<u8>,
+    parts: Vec<Part>,
+    input: Option<Source>,
+    iterations: usize,
+    format: Format,
+    answers: String
+}
+
+fn parse_options(args: &[String]) -> Result<Options, String> {
+    let mut options = Options { day: None, parts: Part::ALL.to_vec(), input: None, iterations: 10, format: Format::Text, answers: "answers.toml".to_string() };
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-p" | "--part" => {
+                let part = args.next()
+                    .and_then(|s| s.parse::<u8>().ok())
+                    .and_then(Part::from_number)
+                    .ok_or("part should be either 1 or 2")?;
+                options.parts = vec![part];
+            }
+            "-i" | "--input" => {
+                options.input = Some(Source::from_arg(args.next().ok_or("expected a path after --input")?));
+            }
+            "-a" | "--answers" => {
+                options.answers = args.next().ok_or("expected a path after --answers")?.clone();
+            }
+            "-n" | "--iterations" => {
+                options.iterations = args.next()
+                    .and_then(|s| s.parse::<usize>().ok())
+                    .filter(|&n| n > 0)
+                    .ok_or("iterations should be a positive number")?;
+            }
+            "-f" | "--format" => {
+                options.format = match args.next().map(String::as_str) {
+                    Some("text") => Format::Text,
+                    Some("json") => Format::Json,
+                    Some("csv") => Format::Csv,
+                    _ => return Err("format should be one of text, json or csv".to_string())
+                };
+            }
+            day if options.day.is_none() => {
+                options.day = Some(day.parse::<u8>().map_err(|_| format!("'{}' is not a valid day", day))?);
+            }
+            other => return Err(format!("unexpected argument '{}'", other))
+        }
+    }
+
+    Ok(options)
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{:.3}ms", bench::as_millis(duration))
+}
+
+fn print_report(report: &Report) {
+    println!("Day {} (parsed in {})", report.day, format_duration(report.parse_time));
+    for part in report.parts.iter() {
+        let answer = part.answer.to_string();
+        // Multi-line answers such as day 10's CRT image are easier to read starting on their own line
+        let separator = if answer.contains('\n') { "\n" } else { " " };
+        println!("  Part {} ({}):{}{}", part.part.number(), format_duration(part.elapsed), separator, answer.trim_end());
+    }
+}
+
+fn format_stats(stats: &Stats) -> String {
+    format!("min {}, median {}, max {}", format_duration(stats.min), format_duration(stats.median), format_duration(stats.max))
+}
+
+fn print_bench_report(report: &BenchReport) {
+    println!("Day {} ({} iterations)", report.day, report.iterations);
+    println!("  Parse:  {}", format_stats(&report.parse));
+    for (part, stats) in report.parts.iter() {
+        println!("  Part {}: {}", part.number(), format_stats(stats));
+    }
+}
+
+fn select_solutions(options: &Options) -> Result<Vec<Box<dyn Solution>>, String> {
+    let solutions = match options.day {
+        Some(day) => vec![days::get(day).ok_or(format!("day {} has no registered solution", day))?],
+        None => days::solutions()
+    };
+    if options.input.is_some() && solutions.len() > 1 {
+        return Err("--input can only be used when running a single day".to_string());
+    }
+
+    Ok(solutions)
+}
+
+fn run(options: Options) -> Result<(), String> {
+    if options.format == Format::Csv {
+        return Err("csv output is only supported by bench".to_string());
+    }
+    let solutions = select_solutions(&options)?;
+    let mut cache = InputCache::new();
+    for solution in solutions {
+        let source = options.input.clone().unwrap_or_else(|| Source::for_day(solution.day()));
+        let input = cache.get(&source).map_err(|e| e.to_string())?;
+        let report = solution.run(input, &options.parts).map_err(|e| format!("could not parse {}: {}", source, e))?;
+        match options.format {
+            Format::Json => report.to_json_records().iter().for_each(|record| println!("{}", record)),
+            _ => print_report(&report)
+        }
+    }
+
+    Ok(())
+}
+
+fn bench(options: Options) -> Result<(), String> {
+    let solutions = select_solutions(&options)?;
+    let mut cache = InputCache::new();
+    let mut reports = Vec::new();
+    if options.format == Format::Csv {
+        println!("{}", bench::CSV_HEADER);
+    }
+    for solution in solutions {
+        let source = options.input.clone().unwrap_or_else(|| Source::for_day(solution.day()));
+        let input = cache.get(&source).map_err(|e| e.to_string())?;
+        let report = bench::bench(solution.as_ref(), input, &options.parts, options.iterations)
+            .map_err(|e| format!("could not parse {}: {}", source, e))?;
+        // Text and CSV are printed as each day finishes since benchmarking every day can take a while
+        match options.format {
+            Format::Text => print_bench_report(&report),
+            Format::Csv => report.to_csv_rows().iter().for_each(|row| println!("{}", row)),
+            Format::Json => reports.push(report.to_json())
+        }
+    }
+    if options.format == Format::Json {
+        println!("{}", Json::Array(reports));
+    }
+
+    Ok(())
+}
+
+/// Returns whether every part that was run matched its recorded answer
+fn verify(options: Options) -> Result<bool, String> {
+    let answers = std::fs::read_to_string(&options.answers)
+        .map_err(|e| format!("could not read '{}': {}", options.answers, e))?
+        .parse::<Answers>()
+        .map_err(|e| format!("could not parse '{}': {}", options.answers, e))?;
+    let solutions = match options.day {
+        Some(_) => select_solutions(&options)?,
+        None => days::solutions().into_iter().filter(|solution| answers.days().contains(&solution.day())).collect()
+    };
+
+    let mut cache = InputCache::new();
+    let mut all_passed = true;
+    for solution in solutions {
+        let source = options.input.clone().unwrap_or_else(|| Source::for_day(solution.day()));
+        let input = cache.get(&source).map_err(|e| e.to_string())?;
+        let report = solution.run(input, &options.parts).map_err(|e| format!("could not parse {}: {}", source, e))?;
+        println!("Day {}", report.day);
+        for part in report.parts.iter() {
+            let verdict = match answers.check(report.day, part.part, &part.answer) {
+                Verdict::Pass => "pass".to_string(),
+                Verdict::Unrecorded => format!("no recorded answer, got {}", part.answer),
+                Verdict::Fail { expected } => {
+                    all_passed = false;
+                    match expected {
+                        // Grids are easier to compare when each starts on its own line
+                        Answer::Grid(_) => format!("FAIL, expected\n{}\ngot\n{}", expected, part.answer),
+                        _ => format!("FAIL, expected {}, got {}", expected, part.answer)
+                    }
+                }
+            };
+            println!("  Part {}: {}", part.part.number(), verdict);
+        }
+    }
+
+    Ok(all_passed)
+}
+
+fn main() -> ExitCode {
+    let args = std::env::args().skip(1).collect::<Vec<String>>();
+    if args.is_empty() || args.iter().any(|s| s == "--help") {
+        println!("{}", USAGE);
+        return ExitCode::SUCCESS;
+    }
+
+    let result = match args[0].as_str() {
+        "run" => parse_options(&args[1..]).and_then(run),
+        "bench" => parse_options(&args[1..]).and_then(bench),
+        "verify" => match parse_options(&args[1..]).and_then(verify) {
+            Ok(true) => Ok(()),
+            Ok(false) => Err("some answers did not match".to_string()),
+            Err(e) => Err(e)
+        },
+        "list" => {
+            for solution in days::solutions() {
+                println!("day {}", solution.day());
+            }
+            Ok(())
+        }
+        other => Err(format!("unknown command '{}'; run with --help for info", other))
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
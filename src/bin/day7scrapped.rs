@@ -45,7 +45,7 @@ impl FileSystem {
         filesystem.add_data("dir ");
         for output in outputs.lines() {
             if output.contains("$ cd") {
-                filesystem.change_directory(&output[2..].split_once(' ').unwrap().1);
+                filesystem.change_directory(output[2..].split_once(' ').unwrap().1);
             } else if !output.contains("$ ls") {
                 filesystem.add_data(output);
             }
@@ -92,10 +92,10 @@ impl FileSystem {
     fn list_directory(&self) -> String {
         let path_string = self.path();
         let current_path = path_string.as_str();
-        let current_depth = FileSystem::path_to_depth(&current_path);
+        let current_depth = FileSystem::path_to_depth(current_path);
         let mut result = String::new();
         self.files.borrow().iter().filter(|(file_path, _)| {
-            file_path.contains(current_path) && FileSystem::path_to_depth(&file_path) == current_depth
+            file_path.contains(current_path) && FileSystem::path_to_depth(file_path) == current_depth
         }).for_each(|(path, file_type)| {
             let to_concat = path.as_str().to_owned();
             let s = match file_type {
@@ -114,11 +114,11 @@ impl FileSystem {
         let default = self.path();
         let default_str = default.as_str();
         let current_path = path.unwrap_or(default_str);
-        let current_depth = FileSystem::path_to_depth(&current_path);
+        let current_depth = FileSystem::path_to_depth(current_path);
         self.files.borrow().keys()
         .filter(|file_path| file_path.as_str() != current_path)
         .filter(|file_path| {
-            file_path.contains(current_path) && FileSystem::path_to_depth(&file_path) == current_depth + 1
+            file_path.contains(current_path) && FileSystem::path_to_depth(file_path) == current_depth + 1
         }).map(|s| s.to_owned()).collect::<Vec<String>>()
     }
 
@@ -132,7 +132,7 @@ impl FileSystem {
                 let child_paths = self.get_child_paths(Some(path));
                 for child in child_paths.iter() {
                     // if child != compare { size += self.get_size(Some(&child)); }
-                    size += self.get_size(Some(&child));
+                    size += self.get_size(Some(child));
                 }
                 size
             }
@@ -142,10 +142,7 @@ impl FileSystem {
     }
 
     fn path_is_directory(&self, path: &str) -> bool {
-        match self.files.borrow().get(path) {
-            Some(FileType::Directory) => true,
-            _ => false
-        }
+        matches!(self.files.borrow().get(path), Some(FileType::Directory))
     }
 
     fn print_directory(&self) {
@@ -162,7 +159,7 @@ impl FileSystem {
 
     fn path_to_depth(path: &str) -> usize {
         if path == "/" { return 1; }
-        path.chars().into_iter().filter(|&c| c == '/').count() + 1
+        path.chars().filter(|&c| c == '/').count() + 1
     }
 }
 
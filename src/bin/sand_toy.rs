@@ -1,4 +1,5 @@
 use std::{io, time::{Duration, Instant}};
+use advent_of_code2022::point::Point;
 use advent_of_code2022::sand_stuff::*;
 use crossterm::{self, terminal, execute, event::{Event, KeyEvent, KeyCode, KeyEventKind, self, KeyModifiers}, style, cursor, queue};
 
@@ -8,7 +9,7 @@ const DEFAULT_SIMULATION_SPEED: f32 = 30f32;
 const MAX_SIMULATION_SPEED: f32 = 120f32;
 
 fn run<W>(w: &mut W, height: i32, width: i32, simulation_speed: f32) -> io::Result<()> where W: io::Write {
-    let mut canvas = Canvas::new(vec![DrawInstruction::new(&format!("0,{} -> {},{}", height, width, height))], (width as usize / 2, 0));
+    let mut canvas = Canvas::new(vec![DrawInstruction::line((0, height as usize), (width as usize, height as usize))], (width as usize / 2, 0));
     canvas.to_string();
     let mut simulation_speed = simulation_speed;
     
@@ -78,34 +79,26 @@ fn run<W>(w: &mut W, height: i32, width: i32, simulation_speed: f32) -> io::Resu
         
                     // Interact
                     KeyCode::Char('r') => {
-                        if let Some(row) = canvas.map.get((cursor_position.1) as usize) {
-                            match row.get(cursor_position.0 as usize) {
-                                Some(Material::Rock) => {
-                                    canvas.map[(cursor_position.1) as usize][cursor_position.0 as usize] = Material::Air;
-                                },
-                                Some(Material::Air) | Some(Material::Sand) => {
-                                    canvas.map[(cursor_position.1) as usize][cursor_position.0 as usize] = Material::Rock;
-                                },
+                        if let Some(material) = canvas.map.get_mut(Point::from(cursor_position)) {
+                            match material {
+                                Material::Rock => *material = Material::Air,
+                                Material::Air | Material::Sand => *material = Material::Rock,
                                 _ => ()
                             }
                         }
                     }
                     KeyCode::Char('t') => {
-                        for row in canvas.map.iter_mut() {
-                            for index in 0..row.len() {
-                                if row[index] == Material::Sand {
-                                    row[index] = Material::Air;
-                                }
+                        for material in canvas.map.values_mut() {
+                            if *material == Material::Sand {
+                                *material = Material::Air;
                             }
                         }
                     }
                     KeyCode::Char('y') => {
-                        for row in canvas.map.iter_mut() {
-                            for index in 0..row.len() {
-                                row[index] = Material::Air;
-                            }
+                        for material in canvas.map.values_mut() {
+                            *material = Material::Air;
                         }
-                        canvas.draw_rocks(DrawInstruction::new(&format!("0,{} -> {},{}", height, width, height)));
+                        canvas.draw_rocks(DrawInstruction::line((0, height as usize), (width as usize, height as usize)));
                     }
                     KeyCode::Char('b') => {
                         brush_mode = !brush_mode;
@@ -116,7 +109,7 @@ fn run<W>(w: &mut W, height: i32, width: i32, simulation_speed: f32) -> io::Resu
                         }
                     }
                     KeyCode::Char(' ') | KeyCode::Enter if canvas.active_sand.is_none() => {
-                        match canvas.map[(cursor_position.1) as usize][cursor_position.0 as usize] {
+                        match canvas.map[Point::from(cursor_position)] {
                             Material::Air | Material::SandSource => {
                                 canvas.source_coord = (cursor_position.0 as usize, (cursor_position.1) as usize);
                                 canvas.add_sand()
@@ -145,7 +138,7 @@ fn run<W>(w: &mut W, height: i32, width: i32, simulation_speed: f32) -> io::Resu
         cursor_position.1 = 0.max((height).min(cursor_position.1));
 
         if brush_mode {
-            canvas.map[cursor_position.1 as usize][cursor_position.0 as usize] = Material::Rock;
+            canvas.map[Point::from(cursor_position)] = Material::Rock;
         }
     }
 
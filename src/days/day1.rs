@@ -0,0 +1,113 @@
+use crate::error::{ParseError, parse_number};
+use crate::solver::{Answer, Solver};
+use crate::utils::paragraphs;
+
+#[derive(Debug, PartialEq)]
+pub struct Elf {
+    pub number: usize,
+    pub calories: i32
+}
+
+// Take input, turn into index (+1) of nth elf and how much food they're carrying
+pub fn parse(input: &str) -> Result<Vec<Elf>, ParseError> {
+    let mut elves = Vec::new();
+    for (index, (start, paragraph)) in paragraphs(input).enumerate() {
+        let mut calories = 0;
+        for (line_number, line) in paragraph.lines().enumerate() {
+            calories += parse_number::<i32>(line, line.trim()).map_err(|e| e.offset_lines(start + line_number))?;
+        }
+        elves.push(Elf{ number: index + 1, calories });
+    }
+    if elves.is_empty() {
+        return Err(ParseError::missing(1, 1, "the calories carried by an elf"));
+    }
+    if elves.len() < 3 {
+        return Err(ParseError::missing(input.lines().count() + 1, 1, "the calories carried by at least three elves"));
+    }
+    Ok(elves)
+}
+
+pub fn get_top_elf(elves: &[Elf]) -> &Elf {
+    elves.iter().max_by(|a, b| a.calories.cmp(&b.calories)).unwrap()
+}
+
+pub fn top_three_elves(elves: &[Elf]) -> (&Elf, &Elf, &Elf) {
+    let mut elves = elves.iter().collect::<Vec<&Elf>>();
+    elves.sort_by(|a, b| {
+        b.calories.cmp(&a.calories)
+    });
+    let mut top = elves.into_iter().take(3);
+    (top.next().unwrap(), top.next().unwrap(), top.next().unwrap())
+}
+
+/// The most calories carried by a single elf
+pub fn part_1(elves: &[Elf]) -> i32 {
+    get_top_elf(elves).calories
+}
+
+/// The total calories carried by the three elves carrying the most
+pub fn part_2(elves: &[Elf]) -> i32 {
+    let (first, second, third) = top_three_elves(elves);
+    first.calories + second.calories + third.calories
+}
+
+pub struct Day1;
+
+impl Solver for Day1 {
+    const DAY: u8 = 1;
+    type Input = Vec<Elf>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(&self, elves: &Self::Input) -> Answer {
+        part_1(elves).into()
+    }
+
+    fn part_2(&self, elves: &Self::Input) -> Answer {
+        part_2(elves).into()
+    }
+}
+
+/// Example calorie list from the puzzle description
+pub const EXAMPLE: &str =
+"1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000";
+
+#[test]
+fn day_1_part_1() {
+    let input = EXAMPLE;
+    assert_eq!(24000, get_top_elf(&parse(input).unwrap()).calories);
+}
+
+#[test] 
+fn day_1_part_2() {
+    let input = EXAMPLE;
+    let elves = parse(input).unwrap();
+    let (first, second, third) = top_three_elves(&elves);
+    assert_eq!(&Elf{ number: 4, calories: 24000 }, first);
+    assert_eq!(&Elf{ number: 3, calories: 11000 }, second);
+    assert_eq!(&Elf{ number: 5, calories: 10000 }, third);
+    assert_eq!(45000, first.calories + second.calories + third.calories);
+}
+
+#[test]
+fn day_1_parse_error() {
+    let error = parse("1000\n2000\n\n30o0").unwrap_err();
+    assert_eq!(ParseError::invalid_number(4, 1, "30o0"), error);
+    assert_eq!(Err(ParseError::missing(1, 1, "the calories carried by an elf")), parse("\n").map(|_| ()));
+    assert_eq!(Err(ParseError::missing(4, 1, "the calories carried by at least three elves")), parse("100\n\n200").map(|_| ()));
+}
@@ -0,0 +1,495 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+use crate::error::{ParseError, column_of, parse_lines, parse_number};
+use crate::grid::Grid;
+use crate::point::Point;
+use crate::solver::{Answer, Solver};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Register {
+    W,
+    X,
+    Y,
+    Z
+}
+
+impl Register {
+    pub const ALL: [Register; 4] = [Register::W, Register::X, Register::Y, Register::Z];
+
+    pub fn name(&self) -> char {
+        match self {
+            Register::W => 'w',
+            Register::X => 'x',
+            Register::Y => 'y',
+            Register::Z => 'z'
+        }
+    }
+}
+
+/// Parses a register by its lowercase name
+impl FromStr for Register {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Register::ALL.into_iter()
+            .find(|register| s.len() == 1 && s.starts_with(register.name()))
+            .ok_or_else(|| ParseError::unexpected(1, 1, s, "a register, one of w, x, y or z"))
+    }
+}
+
+/// Value of every register, where `X` starts at 1 and the rest at 0
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Registers([i64; Register::ALL.len()]);
+
+impl Registers {
+    /// Register the sprite position and signal strength are taken from
+    pub fn x(&self) -> i64 {
+        self[Register::X]
+    }
+}
+
+impl Default for Registers {
+    fn default() -> Self {
+        let mut registers = Registers([0; Register::ALL.len()]);
+        registers[Register::X] = 1;
+        registers
+    }
+}
+
+impl Index<Register> for Registers {
+    type Output = i64;
+
+    fn index(&self, register: Register) -> &Self::Output {
+        &self.0[register as usize]
+    }
+}
+
+impl IndexMut<Register> for Registers {
+    fn index_mut(&mut self, register: Register) -> &mut Self::Output {
+        &mut self.0[register as usize]
+    }
+}
+
+/// Instructions of the puzzle, `noop` and `addx`, along with a few more that work on any register
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Noop,
+    /// Adds to a register, written `addx n` for `X` or `add r n` for any register
+    Add(Register, i64),
+    /// Sets a register, written `set r n`
+    Set(Register, i64),
+    /// Multiplies a register, written `mul r n`
+    Mul(Register, i64)
+}
+
+impl Instruction {
+    /// Number of cycles the instruction takes, with its effect applied at the end of the last one
+    pub fn cycles(&self) -> usize {
+        match self {
+            Instruction::Noop | Instruction::Set(..) => 1,
+            Instruction::Add(..) => 2,
+            Instruction::Mul(..) => 3
+        }
+    }
+
+    fn execute(&self, registers: &mut Registers) {
+        match *self {
+            Instruction::Noop => {}
+            Instruction::Add(register, n) => registers[register] += n,
+            Instruction::Set(register, n) => registers[register] = n,
+            Instruction::Mul(register, n) => registers[register] *= n
+        }
+    }
+}
+
+/// Parses `noop`, `addx n`, `add r n`, `set r n` or `mul r n`
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let words = s.split_whitespace().collect::<Vec<&str>>();
+        let register = |word: &str| word.parse::<Register>().map_err(|e| e.offset_columns(column_of(s, word) - 1));
+        match words[..] {
+            ["noop"] => Ok(Instruction::Noop),
+            ["addx", n] => Ok(Instruction::Add(Register::X, parse_number(s, n)?)),
+            ["add", r, n] => Ok(Instruction::Add(register(r)?, parse_number(s, n)?)),
+            ["set", r, n] => Ok(Instruction::Set(register(r)?, parse_number(s, n)?)),
+            ["mul", r, n] => Ok(Instruction::Mul(register(r)?, parse_number(s, n)?)),
+            _ => Err(ParseError::unexpected(1, 1, s, "one of 'noop', 'addx n', 'add r n', 'set r n' or 'mul r n'"))
+        }
+    }
+}
+
+/// Called on every cycle of a [`Cpu`] with the (1-based) number of the cycle and the registers during it
+pub trait Observer {
+    fn observe(&mut self, cycle: usize, registers: &Registers);
+}
+
+impl<F: FnMut(usize, &Registers)> Observer for F {
+    fn observe(&mut self, cycle: usize, registers: &Registers) {
+        self(cycle, registers)
+    }
+}
+
+/// Runs a program one cycle at a time, letting observers see the registers during every cycle
+pub struct Cpu<'a> {
+    program: &'a [Instruction],
+    pub registers: Registers,
+    /// Number of cycles completed
+    pub cycle: usize,
+    /// Index of the instruction being executed
+    counter: usize,
+    /// Cycles already spent on the instruction being executed
+    spent: usize
+}
+
+impl<'a> Cpu<'a> {
+    pub fn new(program: &'a [Instruction]) -> Self {
+        Cpu { program, registers: Registers::default(), cycle: 0, counter: 0, spent: 0 }
+    }
+
+    /// Whether every instruction has been executed
+    pub fn is_halted(&self) -> bool {
+        self.counter >= self.program.len()
+    }
+
+    /// Runs a single cycle, returning `false` without doing anything if the program has finished
+    pub fn step(&mut self, observers: &mut [&mut dyn Observer]) -> bool {
+        let instruction = match self.program.get(self.counter) {
+            Some(instruction) => instruction,
+            None => return false
+        };
+        self.cycle += 1;
+        for observer in observers.iter_mut() {
+            observer.observe(self.cycle, &self.registers);
+        }
+        self.spent += 1;
+        if self.spent == instruction.cycles() {
+            instruction.execute(&mut self.registers);
+            self.counter += 1;
+            self.spent = 0;
+        }
+
+        true
+    }
+
+    /// Runs the rest of the program, returning the number of cycles it took
+    pub fn run(&mut self, observers: &mut [&mut dyn Observer]) -> usize {
+        while self.step(observers) {}
+        self.cycle
+    }
+}
+
+/// Signal strength, the cycle number multiplied by `X`, during each of a set of cycles
+pub struct SignalStrength {
+    cycles: Vec<usize>,
+    pub strengths: Vec<i64>
+}
+
+impl SignalStrength {
+    pub fn at<I: IntoIterator<Item = usize>>(cycles: I) -> Self {
+        SignalStrength { cycles: cycles.into_iter().collect(), strengths: Vec::new() }
+    }
+
+    pub fn total(&self) -> i64 {
+        self.strengths.iter().sum()
+    }
+}
+
+impl Observer for SignalStrength {
+    fn observe(&mut self, cycle: usize, registers: &Registers) {
+        if self.cycles.contains(&cycle) {
+            self.strengths.push(cycle as i64 * registers.x());
+        }
+    }
+}
+
+/// Screen drawing a pixel per cycle, row by row, lit if the 3 pixel wide sprite centred on `X` covers it
+pub struct Crt {
+    pub screen: Grid<char>
+}
+
+impl Crt {
+    pub fn new(width: usize, height: usize) -> Self {
+        Crt { screen: Grid::new(width, height, '.') }
+    }
+}
+
+/// The puzzle's 40 by 6 screen
+impl Default for Crt {
+    fn default() -> Self {
+        Crt::new(40, 6)
+    }
+}
+
+impl Observer for Crt {
+    fn observe(&mut self, cycle: usize, registers: &Registers) {
+        let index = cycle - 1;
+        let (x, y) = (index % self.screen.width(), index / self.screen.width());
+        if y < self.screen.height() && (registers.x() - x as i64).abs() <= 1 {
+            self.screen[Point::new(x as i32, y as i32)] = '#';
+        }
+    }
+}
+
+/// Image on the screen, with `#` for lit pixels and `.` for dark ones
+impl fmt::Display for Crt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.screen)
+    }
+}
+
+/// Sum of the signal strengths during the 20th, 60th, 100th, 140th, 180th and 220th cycles
+pub fn part_1(commands: &[Instruction]) -> i64 {
+    let mut signal = SignalStrength::at((20..=220).step_by(40));
+    Cpu::new(commands).run(&mut [&mut signal]);
+    signal.total()
+}
+
+/// Image drawn on the CRT, with `#` for lit pixels and `.` for dark ones
+pub fn part_2(commands: &[Instruction]) -> String {
+    let mut crt = Crt::default();
+    Cpu::new(commands).run(&mut [&mut crt]);
+    crt.to_string()
+}
+
+pub fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse_lines(input)
+}
+
+pub struct Day10;
+
+impl Solver for Day10 {
+    const DAY: u8 = 10;
+    type Input = Vec<Instruction>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(&self, commands: &Self::Input) -> Answer {
+        part_1(commands).into()
+    }
+
+    fn part_2(&self, commands: &Self::Input) -> Answer {
+        Answer::grid(&part_2(commands))
+    }
+}
+
+/// Larger example program from the puzzle description
+pub const EXAMPLE: &str =
+"addx 15
+addx -11
+addx 6
+addx -3
+addx 5
+addx -1
+addx -8
+addx 13
+addx 4
+noop
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx -35
+addx 1
+addx 24
+addx -19
+addx 1
+addx 16
+addx -11
+noop
+noop
+addx 21
+addx -15
+noop
+noop
+addx -3
+addx 9
+addx 1
+addx -3
+addx 8
+addx 1
+addx 5
+noop
+noop
+noop
+noop
+noop
+addx -36
+noop
+addx 1
+addx 7
+noop
+noop
+noop
+addx 2
+addx 6
+noop
+noop
+noop
+noop
+noop
+addx 1
+noop
+noop
+addx 7
+addx 1
+noop
+addx -13
+addx 13
+addx 7
+noop
+addx 1
+addx -33
+noop
+noop
+noop
+addx 2
+noop
+noop
+noop
+addx 8
+noop
+addx -1
+addx 2
+addx 1
+noop
+addx 17
+addx -9
+addx 1
+addx 1
+addx -3
+addx 11
+noop
+noop
+addx 1
+noop
+addx 1
+noop
+noop
+addx -13
+addx -19
+addx 1
+addx 3
+addx 26
+addx -30
+addx 12
+addx -1
+addx 3
+addx 1
+noop
+noop
+noop
+addx -9
+addx 18
+addx 1
+addx 2
+noop
+noop
+addx 9
+noop
+noop
+noop
+addx -1
+addx 2
+addx -37
+addx 1
+addx 3
+noop
+addx 15
+addx -21
+addx 22
+addx -6
+addx 1
+noop
+addx 2
+addx 1
+noop
+addx -10
+noop
+noop
+addx 20
+addx 1
+addx 2
+addx 2
+addx -6
+addx -11
+noop
+noop
+noop";
+
+/// Image drawn on the CRT by [`EXAMPLE`]
+pub const EXAMPLE_IMAGE: &str =
+"##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....
+";
+
+#[test]
+fn day_10_part_1() {
+    let test_commands_small = "noop
+addx 3
+addx -5";
+    let expected_states = [1, 1, 1, 4, 4];
+    let commands = parse(test_commands_small).unwrap();
+    let mut states = Vec::new();
+    let mut record = |_: usize, registers: &Registers| states.push(registers.x());
+    let mut cpu = Cpu::new(&commands);
+    assert_eq!(5, cpu.run(&mut [&mut record]));
+    assert_eq!(expected_states.to_vec(), states);
+    assert_eq!(-1, cpu.registers.x());
+
+    let test_commands = parse(EXAMPLE).unwrap();
+    let expected_values = vec![420, 1140, 1800, 2940, 2880, 3960];
+    let expected_sum: i64 = expected_values.iter().sum();
+    let mut signal = SignalStrength::at((20..=220).step_by(40));
+    Cpu::new(&test_commands).run(&mut [&mut signal]);
+
+    assert_eq!(expected_values, signal.strengths);
+    assert_eq!(expected_sum, signal.total());
+    assert_eq!(expected_sum, part_1(&test_commands));
+}
+
+#[test]
+fn day_10_part_2() {
+    let test_commands = parse(EXAMPLE).unwrap();
+    let test_image = EXAMPLE_IMAGE;
+
+    assert_eq!(test_image, part_2(&test_commands));
+}
+
+#[test]
+fn day_10_cpu() {
+    let commands = parse("set y 3\nmul y 4\nadd z -2\naddx 5\nnoop").unwrap();
+    assert_eq!(Instruction::Add(Register::X, 5), commands[3]);
+    assert_eq!(vec![1, 3, 2, 2, 1], commands.iter().map(Instruction::cycles).collect::<Vec<usize>>());
+
+    // Both observers see every cycle, in order
+    let mut y_values = Vec::new();
+    let mut record = |cycle: usize, registers: &Registers| y_values.push((cycle, registers[Register::Y]));
+    let mut crt = Crt::new(3, 3);
+    let mut cpu = Cpu::new(&commands);
+    assert_eq!(9, cpu.run(&mut [&mut record, &mut crt]));
+    assert_eq!(vec![(1, 0), (2, 3), (3, 3), (4, 3), (5, 12), (6, 12), (7, 12), (8, 12), (9, 12)], y_values);
+    assert_eq!((12, -2, 6, 0), (cpu.registers[Register::Y], cpu.registers[Register::Z], cpu.registers.x(), cpu.registers[Register::W]));
+    assert_eq!("###\n###\n##.\n", crt.to_string());
+    assert!(cpu.is_halted() && !cpu.step(&mut []));
+}
+
+#[test]
+fn day_10_parse_error() {
+    assert_eq!(Err(ParseError::unexpected(2, 1, "addy 3", "one of 'noop', 'addx n', 'add r n', 'set r n' or 'mul r n'")), parse("noop\naddy 3"));
+    assert_eq!(Err(ParseError::invalid_number(1, 6, "three")), parse("addx three"));
+    assert_eq!(Err(ParseError::unexpected(1, 5, "q", "a register, one of w, x, y or z")), parse("set q 1"));
+}
@@ -0,0 +1,291 @@
+use std::collections::VecDeque;
+use crate::utils::paragraphs;
+use std::str::FromStr;
+use crate::error::{ParseError, column_of, parse_number};
+use crate::scan;
+use crate::solver::{Answer, Solver};
+
+#[derive(Debug, Clone)]
+pub enum Operation {
+    Multiply(i64),
+    Add(i64),
+    Square
+}
+
+/// Parses the right hand side of `new = old * n`, `new = old + n` or `new = old * old`
+impl FromStr for Operation {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let symbols = s.split_whitespace().collect::<Vec<&str>>();
+        match symbols[..] {
+            ["old", "*", "old"] => Ok(Operation::Square),
+            ["old", "*", n] => Ok(Operation::Multiply(parse_number(s, n)?)),
+            ["old", "+", n] => Ok(Operation::Add(parse_number(s, n)?)),
+            _ => Err(ParseError::unexpected(1, 1, s, "'old * n', 'old + n' or 'old * old'"))
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    pub id: usize,
+    /// Worry levels of the items currently held, in the order they will be inspected
+    pub items: VecDeque<i64>,
+    pub operation: Operation,
+    pub divisor: i64,
+    pub on_true: usize,
+    pub on_false: usize,
+    /// Number of items inspected so far
+    pub inspects: i64
+}
+
+/// Scans the `n`th (0-indexed) line of a monkey's description with `scan`, which is handed the line without
+/// its indentation and `pattern`
+fn scan_line<T, F>(lines: &[&str], n: usize, pattern: &str, scan: F) -> Result<T, ParseError>
+    where F: FnOnce(&str, &str) -> Result<T, ParseError> {
+    let line = lines.get(n).ok_or_else(|| ParseError::missing(n + 1, 1, &format!("'{}'", pattern.split("{}").next().unwrap_or(pattern).trim())))?;
+    let trimmed = line.trim_start();
+    scan(trimmed, pattern).map_err(|e| e.offset_lines(n).offset_columns(column_of(line, trimmed) - 1))
+}
+
+/// Parses the six lines describing a single monkey
+impl FromStr for Monkey {
+    type Err = ParseError;
+
+    fn from_str(info: &str) -> Result<Self, Self::Err> {
+        let lines = info.lines().collect::<Vec<&str>>();
+        let (id,) = scan_line(&lines, 0, "Monkey {}:", |line, pattern| scan!(line, pattern, usize))?;
+        let items = scan_line(&lines, 1, "Starting items: {}", |line, pattern| {
+            scan::fields(line, pattern)?[0]
+                .split(',')
+                .map(|item| parse_number(line, item.trim()))
+                .collect::<Result<VecDeque<i64>, ParseError>>()
+        })?;
+        let operation = scan_line(&lines, 2, "Operation: new = {}", |line, pattern| {
+            let operation = scan::fields(line, pattern)?[0];
+            operation.parse::<Operation>().map_err(|e| e.offset_columns(column_of(line, operation) - 1))
+        })?;
+        let divisor = scan_line(&lines, 3, "Test: divisible by {}", |line, pattern| {
+            let divisor = scan::fields(line, pattern)?[0];
+            match parse_number::<i64>(line, divisor)? {
+                0 => Err(ParseError::unexpected(1, column_of(line, divisor), divisor, "a divisor other than 0")),
+                n => Ok(n)
+            }
+        })?;
+        let (on_true,) = scan_line(&lines, 4, "If true: throw to monkey {}", |line, pattern| scan!(line, pattern, usize))?;
+        let (on_false,) = scan_line(&lines, 5, "If false: throw to monkey {}", |line, pattern| scan!(line, pattern, usize))?;
+        if let Some(extra) = lines.get(6) {
+            let trimmed = extra.trim_start();
+            return Err(ParseError::unexpected(7, column_of(extra, trimmed), trimmed, "the end of the monkey"));
+        }
+
+        Ok(Monkey { id, items, operation, divisor, on_true, on_false, inspects: 0 })
+    }
+}
+
+impl Monkey {
+    /// First item is worry level, second item is monkey to throw to.
+    /// `None` if the worry level grows too large for an `i64` before relief.
+    pub fn inspect<F>(&mut self, relief_method: F)  -> Option<(i64, usize)>
+        where F: Fn(i64) -> i64 {
+        let inspecting = self.items.pop_front().expect("monkey with no items should not be inspecting");
+        self.inspects += 1;
+        let changed = relief_method(match self.operation {
+            Operation::Multiply(n) => inspecting.checked_mul(n),
+            Operation::Add(n) => inspecting.checked_add(n),
+            Operation::Square => inspecting.checked_mul(inspecting)
+        }?);
+        Some((changed, if changed % self.divisor == 0 { self.on_true } else { self.on_false }))
+
+    }
+
+    // Needed this because borrowing two monkeys at once is annoying
+    pub fn inspect_all<F>(&mut self, relief_method: F) -> Option<Vec<(i64, usize)>>
+        where F: Fn(i64) -> i64 {
+        let mut result = Vec::new();
+        while !self.items.is_empty() {
+            result.push(self.inspect(&relief_method)?);
+        }
+
+        Some(result)
+    }
+}
+
+/// Monkeys are described in groups of six lines separated by a blank line, and there have to be at least two of them
+pub fn parse(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let monkeys = paragraphs(input)
+        .map(|(start, info)| info.parse::<Monkey>().map(|monkey| (start, monkey)).map_err(|e| e.offset_lines(start)))
+        .collect::<Result<Vec<(usize, Monkey)>, ParseError>>()?;
+    for (index, (start, monkey)) in monkeys.iter().enumerate() {
+        if monkey.id != index {
+            let line = input.lines().nth(*start).unwrap_or_default();
+            let column = line.find(|c: char| c.is_ascii_digit()).map_or(1, |i| i + 1);
+            return Err(ParseError::unexpected(start + 1, column, &monkey.id.to_string(), &format!("monkey {}, as monkeys are numbered in order", index)));
+        }
+    }
+    for (start, monkey) in monkeys.iter() {
+        if let Some(target) = [monkey.on_true, monkey.on_false].into_iter().find(|&target| target >= monkeys.len()) {
+            let line = start + if target == monkey.on_true { 5 } else { 6 };
+            return Err(ParseError::unexpected(line, 1, &target.to_string(), &format!("a monkey below {}", monkeys.len())));
+        }
+    }
+    if monkeys.len() < 2 {
+        return Err(ParseError::missing(input.lines().count() + 1, 1, "another monkey, as monkey business needs two"));
+    }
+
+    Ok(monkeys.into_iter().map(|(_, monkey)| monkey).collect())
+}
+
+/// Plays the given number of rounds and multiplies the inspection counts of the two most active monkeys,
+/// or `None` if a worry level or the result grows too large for an `i64`
+pub fn monkey_business<F>(monkeys: &[Monkey], rounds: usize, relief_method: F) -> Option<i64>
+    where F: Fn(i64) -> i64 {
+    let mut monkeys = monkeys.to_vec();
+    for _ in 0..rounds {
+        for i in 0..monkeys.len() {
+            let to_throw = monkeys[i].inspect_all(&relief_method)?;
+            for (worry, id) in to_throw {
+                monkeys[id].items.push_back(worry);
+            }
+        }
+    }
+
+    monkeys.sort_by_key(|monkey| std::cmp::Reverse(monkey.inspects));
+    let mut sorted = monkeys.iter();
+    let (first, second) = (sorted.next().unwrap(), sorted.next().unwrap());
+    first.inspects.checked_mul(second.inspects)
+}
+
+/// Monkey business after 20 rounds where worry levels are divided by 3 after each inspection
+pub fn part_1(monkeys: &[Monkey]) -> Option<i64> {
+    monkey_business(monkeys, 20, |n| n / 3)
+}
+
+/// Monkey business after 10000 rounds where worry levels are no longer divided
+pub fn part_2(monkeys: &[Monkey]) -> Option<i64> {
+    // I legitimately would have never figured out how to do part 2 if I didn't look at the subreddit
+    let divisor_product = monkeys.iter().try_fold(1i64, |product, monkey| product.checked_mul(monkey.divisor))?;
+    monkey_business(monkeys, 10000, |n| n % divisor_product)
+}
+
+/// Answer for monkey business that could not be worked out
+fn overflowed(business: Option<i64>) -> Answer {
+    business.map_or_else(|| Answer::from(String::from("worry levels grew too large to keep track of")), Answer::from)
+}
+
+pub struct Day11;
+
+impl Solver for Day11 {
+    const DAY: u8 = 11;
+    type Input = Vec<Monkey>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(&self, monkeys: &Self::Input) -> Answer {
+        overflowed(part_1(monkeys))
+    }
+
+    fn part_2(&self, monkeys: &Self::Input) -> Answer {
+        overflowed(part_2(monkeys))
+    }
+}
+
+/// Example monkeys from the puzzle description
+pub const EXAMPLE: &str =
+"Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1";
+
+#[test]
+fn day_11_new_monkey_test() {
+    let monkey_info = "Monkey 0:
+  Starting items: 85, 79, 63, 72
+  Operation: new = old * 17
+  Test: divisible by 2
+    If true: throw to monkey 2
+    If false: throw to monkey 6";
+    let monkey = monkey_info.parse::<Monkey>().unwrap();
+    println!("{:?}", monkey);
+}
+
+#[test]
+fn day_11_paragraphs_test() {
+    let input = EXAMPLE;
+    let infos = paragraphs(input).collect::<Vec<(usize, &str)>>();
+    assert_eq!(vec![0, 7, 14, 21], infos.iter().map(|&(start, _)| start).collect::<Vec<usize>>());
+    for (_, s) in infos {
+        println!("New monkey:\n {}", s);
+    }
+    // Blank lines with trailing whitespace, or more than one of them, still separate monkeys
+    let spaced = EXAMPLE.replace("\n\n", "\n  \n\n");
+    assert_eq!(parse(input).unwrap().len(), parse(&spaced).unwrap().len());
+}
+
+#[test]
+fn day_11_part_1() {
+    const EXPECTED_MONKEY_BUSINESS: i64 = 10605;
+    let input = EXAMPLE;
+    let monkeys = parse(input).unwrap();
+    assert_eq!(Some(EXPECTED_MONKEY_BUSINESS), monkey_business(&monkeys, 20, |n| n / 3));
+}
+
+#[test]
+fn day_11_part_2() {
+    const EXPECTED_MONKEY_BUSINESS: i64 = 2713310158;
+    let input = EXAMPLE;
+    let monkeys = parse(input).unwrap();
+    let divisor_product = monkeys.iter().map(|monkey| monkey.divisor).product::<i64>();
+    assert_eq!(Some(EXPECTED_MONKEY_BUSINESS), monkey_business(&monkeys, 10000, |n| n % divisor_product));
+    assert_eq!(Some(EXPECTED_MONKEY_BUSINESS), part_2(&monkeys));
+
+    // Squaring keeps going until it no longer fits
+    let huge = parse(&EXAMPLE.replace("Starting items: 79, 60, 97", "Starting items: 99999999")).unwrap();
+    assert_eq!(None, part_1(&huge));
+    assert_eq!(Answer::from(String::from("worry levels grew too large to keep track of")), Day11.part_1(&huge));
+}
+
+#[test]
+fn day_11_parse_error() {
+    let monkey_info = "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old / 19
+  Test: divisible by 23
+    If true: throw to monkey 0
+    If false: throw to monkey 0";
+    assert_eq!(Err(ParseError::unexpected(3, 20, "old / 19", "'old * n', 'old + n' or 'old * old'")), parse(monkey_info).map(|_| ()));
+    assert_eq!(Err(ParseError::invalid_number(2, 23, "9x")), parse(&monkey_info.replace("98", "9x")).map(|_| ()));
+    assert_eq!(Err(ParseError::unexpected(4, 3, "Test:", "'Test: divisible by'")), parse(&monkey_info.replace("/", "*").replace("Test: divisible", "Test:  divisible")).map(|_| ()));
+    assert_eq!(Err(ParseError::missing(6, 1, "'If false: throw to monkey'")), parse(&monkey_info.replace("/", "*").replace("\n    If false: throw to monkey 0", "")).map(|_| ()));
+    assert_eq!(Err(ParseError::unexpected(5, 1, "1", "a monkey below 1")), parse(&monkey_info.replace("/", "*").replace("true: throw to monkey 0", "true: throw to monkey 1")).map(|_| ()));
+    assert_eq!(Err(ParseError::unexpected(4, 22, "0", "a divisor other than 0")), parse(&monkey_info.replace("/", "*").replace("by 23", "by 0")).map(|_| ()));
+    assert_eq!(Err(ParseError::unexpected(7, 3, "Test: divisible by 5", "the end of the monkey")), parse(&(monkey_info.replace("/", "*") + "\n  Test: divisible by 5")).map(|_| ()));
+    assert_eq!(Err(ParseError::missing(7, 1, "another monkey, as monkey business needs two")), parse(&monkey_info.replace("/", "*")).map(|_| ()));
+    assert_eq!(Err(ParseError::unexpected(8, 8, "3", "monkey 1, as monkeys are numbered in order")), parse(&EXAMPLE.replace("Monkey 1:", "Monkey 3:")).map(|_| ()));
+}
@@ -0,0 +1,459 @@
+// Hello A* my old friend,
+// I knew I'd probably see you again
+use std::collections::{HashMap, HashSet};
+use std::io;
+use crossterm::style::{Color, Stylize};
+use crate::error::ParseError;
+use crate::graph::{Graph, Path, a_star, distances};
+use crate::grid::{Grid, NEIGHBOURS_4, NEIGHBOURS_8};
+use crate::point::Point;
+use crate::solver::{Answer, Solver};
+
+#[derive(Debug)]
+pub struct Node<T> {
+    // in a real implementation you'd probably use a UUID or something instead but eh
+    pub id: usize,
+    pub data: T,
+}
+
+impl<T> PartialEq for Node<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+/// Directed graph of nodes, where `node_neighbors[i]` are the indexes of the nodes reachable from `nodes[i]`
+pub struct Map<T> {
+    pub nodes: Vec<Node<T>>,
+    pub node_neighbors: Vec<Vec<usize>>,
+    /// Cost of stepping between the pair of indexes, which is 1 if missing
+    pub edge_costs: HashMap<(usize, usize), u64>,
+    /// Rules the neighbors and edge costs were worked out with, which searches over the map also go by
+    pub rules: MoveRules
+}
+
+impl<T> Map<T> {
+    pub fn set_neighbors(&mut self, a: &Node<T>, b: &Node<T>) {
+        let a = self.get_index_of(a).unwrap();
+        let b = self.get_index_of(b).unwrap();
+        self.set_neighbors_by_index(a, b);
+    }
+
+    pub fn set_neighbors_by_index(&mut self, a: usize, b: usize) {
+        self.node_neighbors[a].push(b);
+        self.node_neighbors[b].push(a);
+    }
+    
+    pub fn get_neighbor_indexes(&self, node: &Node<T>) -> &Vec<usize> {
+        &self.node_neighbors[self.get_index_of(node).unwrap()]
+    }
+
+    pub fn get_index_of(&self, node: &Node<T>) -> Option<usize> {
+        self.nodes.iter().position(|n| n == node)
+    }
+}
+
+/// Nodes are identified by their index in `nodes`
+impl<T> Graph for Map<T> {
+    type Node = usize;
+
+    fn neighbors(&self, node: usize) -> Vec<usize> {
+        self.node_neighbors[node].clone()
+    }
+
+    fn weight(&self, from: usize, to: usize) -> u64 {
+        self.edge_costs.get(&(from, to)).copied().unwrap_or(1)
+    }
+}
+
+#[derive(Debug)]
+pub struct Hill {
+    /// Character from the heightmap, including `S` and `E`
+    pub height: char,
+    pub position: Point<i32>
+}
+
+impl Hill {
+    pub fn calc_height(c: char) -> i32 {
+        match c {
+            'a'..='z' => c as i32,
+            'S' => 'a' as i32,
+            'E' => 'z' as i32,
+            _ => panic!("Invald character given for height")
+        }
+    }
+}
+
+/// Which adjacent hills the climber can step to, and how much each step costs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MoveRules {
+    /// Whether diagonally adjacent hills can be stepped to as well as orthogonal ones
+    pub diagonals: bool,
+    /// Most the height can increase in one step, or `None` for no limit
+    pub max_climb: Option<i32>,
+    /// Most the height can decrease in one step, or `None` for no limit
+    pub max_descent: Option<i32>,
+    /// Cost of every step
+    pub step_cost: u64,
+    /// Extra cost of a step for each unit of height it climbs or descends
+    pub height_cost: u64
+}
+
+/// The rules from the puzzle: orthogonal steps that climb at most one, each costing one
+impl Default for MoveRules {
+    fn default() -> Self {
+        MoveRules { diagonals: false, max_climb: Some(1), max_descent: None, step_cost: 1, height_cost: 0 }
+    }
+}
+
+impl MoveRules {
+    pub fn offsets(&self) -> &'static [(i32, i32)] {
+        if self.diagonals { &NEIGHBOURS_8 } else { &NEIGHBOURS_4 }
+    }
+
+    /// Cost of stepping from `a` to its neighbor `b`, or `None` if the step is not allowed, like [`compare_hill_height`]
+    pub fn step(&self, a: &Node<Hill>, b: &Node<Hill>) -> Option<u64> {
+        let climb = Hill::calc_height(b.data.height) - Hill::calc_height(a.data.height);
+        if self.max_climb.map_or(false, |max| climb > max) || self.max_descent.map_or(false, |max| -climb > max) {
+            return None;
+        }
+
+        Some(self.step_cost.saturating_add(self.height_cost.saturating_mul(climb.unsigned_abs() as u64)))
+    }
+
+    /// Lower bound on the cost of getting from `a` to `target`. Height also needed to be taken into account
+    /// to give it a bias towards moving upwards
+    pub fn heuristic(&self, a: &Node<Hill>, target: &Node<Hill>) -> u64 {
+        let (dx, dy) = ((a.data.position.x - target.data.position.x).abs(), (a.data.position.y - target.data.position.y).abs());
+        let distance = if self.diagonals { dx.max(dy) } else { dx + dy };
+        let climb = Hill::calc_height(target.data.height) - Hill::calc_height(a.data.height);
+        // Every step can only climb or descend so far
+        let limited = match (climb, self.max_climb, self.max_descent) {
+            (climb, Some(max), _) if climb > 0 && max > 0 => (climb + max - 1) / max,
+            (climb, _, Some(max)) if climb < 0 && max > 0 => (-climb + max - 1) / max,
+            _ => 0
+        };
+
+        distance.max(limited) as u64 * self.step_cost + climb.unsigned_abs() as u64 * self.height_cost
+    }
+}
+
+/// Builds the map from a heightmap, where each hill's neighbors are the adjacent hills `rules` allow stepping to.
+/// Each hill's id is its index in the grid.
+pub fn construct_map(heights: &Grid<char>, rules: &MoveRules) -> Map<Hill> {
+    let nodes = heights.iter()
+        .enumerate()
+        .map(|(id, (position, &height))| Node { id, data: Hill { height, position } })
+        .collect::<Vec<Node<Hill>>>();
+
+    let mut node_neighbors = Vec::new();
+    let mut edge_costs = HashMap::new();
+    for hill in nodes.iter() {
+        let mut neighbors = Vec::new();
+        for position in heights.neighbours(hill.data.position, rules.offsets()) {
+            let index = heights.index_of(position).expect("neighbour should be within the grid");
+            if let Some(cost) = rules.step(hill, &nodes[index]) {
+                neighbors.push(index);
+                edge_costs.insert((hill.id, index), cost);
+            }
+        }
+        node_neighbors.push(neighbors);
+    }
+
+    Map { nodes, node_neighbors, edge_costs, rules: *rules }
+}
+
+/// Distance between two neighboring hills, or `None` if `b` is too high to climb to from `a`
+pub fn compare_hill_height(a: &Node<Hill>, b: &Node<Hill>) -> Option<u64> {
+    if Hill::calc_height(b.data.height) - Hill::calc_height(a.data.height) < 2 {
+        Some(1)
+    } else {
+        None
+    }
+}
+
+/// Cheapest path from `start` to `target` under the map's rules, or `None` if `target` cannot be reached
+pub fn shortest_path(map: &Map<Hill>, start: &Node<Hill>, target: &Node<Hill>) -> Option<Path<usize>> {
+    a_star(map, start.id, |id| id == target.id, |id| map.rules.heuristic(&map.nodes[id], target))
+}
+
+impl<'a> Map<Hill> {
+    pub fn get_start(&'a self) -> &'a Node<Hill> {
+        self.nodes.iter().find(|n| n.data.height == 'S').unwrap()
+    }
+
+    pub fn get_end(&'a self) -> &'a Node<Hill> {
+        self.nodes.iter().find(|n| n.data.height == 'E').unwrap()
+    }
+}
+
+/// Map walked backwards, where stepping from `b` to `a` is allowed if the map's rules allow stepping from `a` to `b`
+struct Reversed<'a> {
+    map: &'a Map<Hill>,
+    ids: HashMap<Point<i32>, usize>
+}
+
+impl<'a> Graph for Reversed<'a> {
+    type Node = usize;
+
+    fn neighbors(&self, node: usize) -> Vec<usize> {
+        let to = &self.map.nodes[node];
+        self.map.rules.offsets().iter()
+            .filter_map(|&offset| self.ids.get(&to.data.position.add_tuple(offset)))
+            .filter(|&&from| self.map.rules.step(&self.map.nodes[from], to).is_some())
+            .copied()
+            .collect()
+    }
+
+    fn weight(&self, to: usize, from: usize) -> u64 {
+        self.map.rules.step(&self.map.nodes[from], &self.map.nodes[to]).expect("step backwards should be allowed forwards")
+    }
+}
+
+/// Cost of the cheapest path under the map's rules from every hill to `target`, keyed by hill id,
+/// found with a single search backwards from `target`. Hills that cannot reach `target` are left out.
+pub fn distances_from(map: &Map<Hill>, target: &Node<Hill>) -> HashMap<usize, u64> {
+    let ids = map.nodes.iter().map(|node| (node.data.position, node.id)).collect();
+    distances(&Reversed { map, ids }, target.id)
+}
+
+/// Finds the shortest path from any hill of height 'a' to the end, or `None` if none of them can reach it
+pub fn shortest_from_lowest(map: &Map<Hill>) -> Option<usize> {
+    let distances = distances_from(map, map.get_end());
+    map.nodes.iter()
+        .filter(|node| node.data.height == 'a')
+        .filter_map(|node| distances.get(&node.id))
+        .min()
+        .map(|&steps| steps as usize)
+}
+
+/// Characters of the heightmap the map was constructed from
+fn heightmap(map: &Map<Hill>) -> Grid<char> {
+    let width = map.nodes.iter().map(|n| n.data.position.x + 1).max().unwrap_or(0) as usize;
+    let height = map.nodes.iter().map(|n| n.data.position.y + 1).max().unwrap_or(0) as usize;
+    let mut grid = Grid::new(width, height, ' ');
+    for node in map.nodes.iter() {
+        grid[node.data.position] = node.data.height;
+    }
+
+    grid
+}
+
+/// Arrow drawn for a step towards each of the offsets in [`NEIGHBOURS_8`], in the same order
+pub const ARROWS: [char; 8] = ['^', '↗', '>', '↘', 'v', '↙', '<', '↖'];
+
+/// Heightmap with the steps of `path` drawn over it as [`ARROWS`], each pointing towards the next hill.
+/// The last hill of the path keeps its height.
+pub fn path_grid(map: &Map<Hill>, path: &[usize]) -> Grid<char> {
+    let mut grid = heightmap(map);
+    for step in path.windows(2) {
+        let (from, to) = (map.nodes[step[0]].data.position, map.nodes[step[1]].data.position);
+        let offset = ((to.x - from.x).signum(), (to.y - from.y).signum());
+        grid[from] = NEIGHBOURS_8.iter().position(|&neighbour| neighbour == offset).map_or('?', |index| ARROWS[index]);
+    }
+
+    grid
+}
+
+/// Colour of a hill on a scale from dark green at `a` to white at `z`
+fn height_colour(c: char) -> Color {
+    let t = (Hill::calc_height(c) - 'a' as i32) as f32 / 25f32;
+    let scale = |low: f32| (low + (240f32 - low) * t) as u8;
+    Color::Rgb { r: scale(30f32), g: scale(90f32), b: scale(30f32) }
+}
+
+/// Draws `path` over the heightmap as with [`path_grid`]. If `colour` is set, each hill's background is
+/// coloured by its height and the arrows are highlighted, using terminal escape codes.
+pub fn render_path(map: &Map<Hill>, path: &[usize], colour: bool) -> String {
+    let grid = path_grid(map, path);
+    if !colour {
+        return grid.to_string();
+    }
+    let heights = heightmap(map);
+
+    let on_path = path[..path.len().saturating_sub(1)].iter().map(|&id| map.nodes[id].data.position).collect::<HashSet<Point<i32>>>();
+    let mut s = String::new();
+    for (y, row) in grid.rows().enumerate() {
+        for (x, &c) in row.iter().enumerate() {
+            let position = Point::new(x as i32, y as i32);
+            let background = match heights[position] {
+                ' ' => Color::Reset,
+                height => height_colour(height)
+            };
+            let styled = if on_path.contains(&position) { c.with(Color::DarkRed).bold() } else { c.with(Color::Black) };
+            s += &styled.on(background).to_string();
+        }
+        s.push('\n');
+    }
+
+    s
+}
+
+/// Writes a binary PPM image with one pixel per hill, shading reachable hills from red when close
+/// to blue when far away, by their value in `distances`. Hills without a distance are black.
+pub fn write_heatmap<W: io::Write>(map: &Map<Hill>, distances: &HashMap<usize, u64>, mut w: W) -> io::Result<()> {
+    let heights = heightmap(map);
+    let furthest = distances.values().copied().max().unwrap_or(0).max(1);
+    let mut pixels = vec![0u8; heights.width() * heights.height() * 3];
+    for node in map.nodes.iter() {
+        if let Some(&distance) = distances.get(&node.id) {
+            let t = distance as f32 / furthest as f32;
+            let index = heights.index_of(node.data.position).expect("hill should be within the heightmap") * 3;
+            pixels[index..index + 3].copy_from_slice(&[(255f32 * (1f32 - t)) as u8, 64, (255f32 * t) as u8]);
+        }
+    }
+
+    write!(w, "P6\n{} {}\n255\n", heights.width(), heights.height())?;
+    w.write_all(&pixels)
+}
+
+/// Heightmap of `a` to `z`, with exactly one start `S` and one end `E`
+pub fn parse(input: &str) -> Result<Map<Hill>, ParseError> {
+    let heights = Grid::parse_chars(input, "a height from a to z, S or E", |c| match c {
+        'a'..='z' | 'S' | 'E' => Some(c),
+        _ => None
+    })?;
+    for marker in ['S', 'E'] {
+        let mut found = heights.iter().filter(|&(_, &c)| c == marker).map(|(position, _)| position);
+        if found.next().is_none() {
+            return Err(ParseError::missing(input.lines().count().max(1), 1, &format!("a hill marked '{}'", marker)));
+        }
+        if let Some(position) = found.next() {
+            return Err(ParseError::unexpected(position.y as usize + 1, position.x as usize + 1, &marker.to_string(), "a single start and end"));
+        }
+    }
+
+    Ok(construct_map(&heights, &MoveRules::default()))
+}
+
+/// Fewest steps from `S` to `E`
+pub fn part_1(map: &Map<Hill>) -> usize {
+    shortest_path(map, map.get_start(), map.get_end()).expect("end should be reachable from the start").len()
+}
+
+/// Fewest steps from any hill of height `a` to `E`, or `None` if none of them can reach it
+pub fn part_2(map: &Map<Hill>) -> Option<usize> {
+    shortest_from_lowest(map)
+}
+
+pub struct Day12;
+
+impl Solver for Day12 {
+    const DAY: u8 = 12;
+    type Input = Map<Hill>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(&self, map: &Self::Input) -> Answer {
+        part_1(map).into()
+    }
+
+    fn part_2(&self, map: &Self::Input) -> Answer {
+        part_2(map).map_or_else(|| Answer::from(String::from("no hill of height a can reach the end")), Answer::from)
+    }
+}
+
+#[test]
+fn day_12_part_1() {
+    let map_str = "Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi";
+    let expected_steps = 31;
+    let map = construct_map(&map_str.parse().unwrap(), &MoveRules::default());
+    let start = map.get_start();
+    let end = map.get_end();
+
+    let path = shortest_path(&map, start, end).unwrap();
+    assert_eq!(expected_steps, path.len());
+    assert_eq!(expected_steps as u64, path.cost);
+    assert_eq!(&start.id, path.start());
+
+    println!("{}", render_path(&map, &path.nodes, false));
+    let grid = path_grid(&map, &path.nodes);
+    for step in path.nodes.windows(2) {
+        let from = map.nodes[step[0]].data.position;
+        assert!(['^', 'v', '<', '>'].contains(&grid[from]));
+        let arrow = ARROWS.iter().position(|&c| c == grid[from]).unwrap();
+        assert_eq!(map.nodes[step[1]].data.position, from.add_tuple(NEIGHBOURS_8[arrow]));
+    }
+    assert_eq!('E', grid[end.data.position]);
+}
+
+#[test]
+fn day_12_part_2() {
+    let map_str = "Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi";
+    let expected_steps = 29;
+    let map = construct_map(&map_str.parse().unwrap(), &MoveRules::default());
+
+    assert_eq!(Some(expected_steps), shortest_from_lowest(&map));
+    // The hill of height a is walled off from the end
+    let walled = construct_map(&"Sa\nzE".parse().unwrap(), &MoveRules::default());
+    assert_eq!(None, part_2(&walled));
+    assert_eq!(Answer::from(String::from("no hill of height a can reach the end")), Day12.part_2(&walled));
+
+    // Every other start can be looked up from the same search
+    let distances = distances_from(&map, map.get_end());
+    assert_eq!(Some(&31), distances.get(&map.get_start().id));
+    assert_eq!(Some(&0), distances.get(&map.get_end().id));
+    assert_eq!(map.nodes.len(), distances.len());
+
+    let mut image = Vec::new();
+    write_heatmap(&map, &distances, &mut image).unwrap();
+    assert!(image.starts_with(b"P6\n8 5\n255\n"));
+    assert_eq!("P6\n8 5\n255\n".len() + 8 * 5 * 3, image.len());
+}
+
+#[test]
+fn day_12_move_rules() {
+    let heights = "Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi".parse().unwrap();
+    let standard = construct_map(&heights, &MoveRules::default());
+    for (index, neighbors) in standard.node_neighbors.iter().enumerate() {
+        for &neighbor in neighbors {
+            assert_eq!(Some(1), compare_hill_height(&standard.nodes[index], &standard.nodes[neighbor]));
+        }
+    }
+
+    let cases = [
+        (MoveRules { diagonals: true, ..MoveRules::default() }, 27, 27),
+        // Straight there, as any climb is allowed
+        (MoveRules { max_climb: None, ..MoveRules::default() }, 7, 7),
+        // Still has to climb from a to z on the way
+        (MoveRules { max_climb: None, height_cost: 1, ..MoveRules::default() }, 7, 32),
+        // Too costly to fit in an i32, so climbing any more than needed would never pay off
+        (MoveRules { max_climb: None, height_cost: 1 << 40, ..MoveRules::default() }, 7, 25 * (1 << 40) + 7),
+        (MoveRules { max_climb: Some(2), max_descent: Some(1), step_cost: 2, ..MoveRules::default() }, 27, 54)
+    ];
+    for (rules, expected_steps, expected_cost) in cases {
+        let map = construct_map(&heights, &rules);
+        let path = shortest_path(&map, map.get_start(), map.get_end()).unwrap();
+        println!("{:?}: {} steps costing {}", rules, path.len(), path.cost);
+        assert_eq!((expected_steps, expected_cost), (path.len(), path.cost));
+        assert_eq!(Some(&path.cost), distances_from(&map, map.get_end()).get(&map.get_start().id));
+        // Diagonal steps get arrows of their own
+        let grid = path_grid(&map, &path.nodes);
+        for step in path.nodes.windows(2) {
+            let from = map.nodes[step[0]].data.position;
+            let arrow = ARROWS.iter().position(|&c| c == grid[from]).expect("every step should be drawn as an arrow");
+            assert_eq!(map.nodes[step[1]].data.position, from.add_tuple(NEIGHBOURS_8[arrow]));
+        }
+        assert_eq!(rules.diagonals, path.nodes.windows(2).any(|step| "↗↘↙↖".contains(grid[map.nodes[step[0]].data.position])));
+    }
+}
+
+#[test]
+fn day_12_parse_error() {
+    assert_eq!(Err(ParseError::unexpected(2, 3, "#", "a height from a to z, S or E")), parse("Sab\nab#E").map(|_| ()));
+    assert_eq!(Err(ParseError::missing(1, 1, "a hill marked 'E'")), parse("Sab").map(|_| ()));
+}
@@ -0,0 +1,584 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+use crate::utils::paragraphs;
+use crate::error::{ParseError, parse_number};
+use crate::json::Json;
+use crate::solver::{Answer, Solver};
+
+/// Packet data, either an integer or a list of other packets.
+///
+/// Equality follows the puzzle's ordering, so `3`, `[3]` and `[[3]]` are all equal; compare the packets'
+/// [`Display`](fmt::Display) output to tell their nesting apart
+#[derive(Debug, Clone)]
+pub enum Packet {
+    Int(i64),
+    List(Vec<Packet>)
+}
+
+impl Packet {
+    /// Builds a list of integers, such as the divider packets' `[2]`
+    pub fn list_of(values: &[i64]) -> Self {
+        Packet::List(values.iter().map(|&value| Packet::Int(value)).collect())
+    }
+}
+
+/// Compares packets by the puzzle's rules: integers by value, lists item by item with the shorter list first if
+/// one runs out, and an integer against a list as if the integer was a list holding just it
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Packet::Int(left), Packet::Int(right)) => left.cmp(right),
+            (Packet::List(left), Packet::List(right)) => left.iter().cmp(right.iter()),
+            (Packet::Int(_), Packet::List(right)) => std::slice::from_ref(self).iter().cmp(right.iter()),
+            (Packet::List(left), Packet::Int(_)) => left.iter().cmp(std::slice::from_ref(other).iter())
+        }
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Equal by the ordering rules, as sorting relies on the two agreeing
+impl PartialEq for Packet {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Packet {}
+
+/// Writes the packet the way it appears in the input, without any spaces
+impl fmt::Display for Packet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Packet::Int(value) => write!(f, "{}", value),
+            Packet::List(items) => {
+                write!(f, "[")?;
+                for (index, item) in items.iter().enumerate() {
+                    if index > 0 { write!(f, ",")?; }
+                    write!(f, "{}", item)?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+/// Recursive descent over a single packet, keeping track of the byte position reached
+struct PacketParser<'a> {
+    s: &'a str,
+    position: usize
+}
+
+impl<'a> PacketParser<'a> {
+    fn peek(&self) -> Option<char> {
+        self.s[self.position..].chars().next()
+    }
+
+    fn column(&self) -> usize {
+        self.s[..self.position].chars().count() + 1
+    }
+
+    fn unexpected(&self, c: char, expected: &str) -> ParseError {
+        ParseError::unexpected(1, self.column(), &c.to_string(), expected)
+    }
+
+    fn packet(&mut self) -> Result<Packet, ParseError> {
+        match self.peek() {
+            Some('[') => self.list(),
+            Some('-' | '0'..='9') => self.int(),
+            Some(c) => Err(self.unexpected(c, "a packet of integers and lists")),
+            None => Err(ParseError::missing(1, self.column(), "a packet of integers and lists"))
+        }
+    }
+
+    fn int(&mut self) -> Result<Packet, ParseError> {
+        let rest = &self.s[self.position..];
+        let sign = usize::from(rest.starts_with('-'));
+        let end = rest[sign..].find(|c: char| !c.is_ascii_digit()).map_or(rest.len(), |end| end + sign);
+        let value = parse_number(self.s, &rest[..end])?;
+        self.position += end;
+        Ok(Packet::Int(value))
+    }
+
+    fn list(&mut self) -> Result<Packet, ParseError> {
+        self.position += 1;
+        let mut items = Vec::new();
+        if self.peek() == Some(']') {
+            self.position += 1;
+            return Ok(Packet::List(items));
+        }
+        loop {
+            items.push(self.packet()?);
+            match self.peek() {
+                Some(',') => self.position += 1,
+                Some(']') => {
+                    self.position += 1;
+                    return Ok(Packet::List(items));
+                },
+                Some(c) => return Err(self.unexpected(c, "',' or ']'")),
+                None => return Err(ParseError::missing(1, self.column(), "']'"))
+            }
+        }
+    }
+}
+
+/// Parses a packet, which is always a list at the outermost level
+impl FromStr for Packet {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = PacketParser { s, position: 0 };
+        match parser.peek() {
+            Some('[') => {}
+            Some(c) => return Err(parser.unexpected(c, "a packet of integers and lists")),
+            None => return Err(ParseError::missing(1, 1, "a packet"))
+        }
+        let packet = parser.list()?;
+        if parser.position < s.len() {
+            return Err(ParseError::unexpected(1, parser.column(), &s[parser.position..], "the end of the packet"));
+        }
+
+        Ok(packet)
+    }
+}
+
+/// Which rule decided a comparison
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rule {
+    /// Two different integers
+    Integers,
+    /// Two lists, one of which ran out of items first
+    ListLength
+}
+
+/// Which of the two packets being compared
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    Left,
+    Right
+}
+
+/// Integer that had to be converted to a list holding just itself, to compare it with a list
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Promotion {
+    /// Level of the decision's path that indexes into the new list, or the path's length if the new list was decided on
+    pub level: usize,
+    pub side: Side
+}
+
+/// Comparison that decided the order of two packets
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Decision {
+    /// Index of the item followed at each level, outermost first, to get to the deciding values.
+    /// An integer promoted to a list counts as a level, with only index 0.
+    pub path: Vec<usize>,
+    pub rule: Rule,
+    /// Every promotion on the way to the deciding values, outermost first
+    pub promotions: Vec<Promotion>,
+    /// Values compared, both integers for [`Rule::Integers`] and both lists for [`Rule::ListLength`]
+    pub left: Packet,
+    pub right: Packet
+}
+
+/// Why two packets compare the way they do
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation {
+    pub ordering: Ordering,
+    /// `None` if the packets are equal
+    pub decision: Option<Decision>,
+    /// Every step taken, in the format of the puzzle's own walkthrough
+    pub trace: Vec<String>
+}
+
+/// Writes the trace, one step per line
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for line in &self.trace {
+            writeln!(f, "{}", line)?;
+        }
+        Ok(())
+    }
+}
+
+/// Follows the same rules as `Packet::cmp`, but records every step and where the order was decided
+pub fn explain(left: &Packet, right: &Packet) -> Explanation {
+    fn step(left: &Packet, right: &Packet, depth: usize, path: &mut Vec<usize>, promotions: &mut Vec<Promotion>, trace: &mut Vec<String>) -> Option<Decision> {
+        let indent = "  ".repeat(depth);
+        trace.push(format!("{}- Compare {} vs {}", indent, left, right));
+        let decide = |trace: &mut Vec<String>, path: &[usize], promotions: &[Promotion], rule: Rule, message: &str| {
+            trace.push(format!("{}  - {}", indent, message));
+            Some(Decision { path: path.to_vec(), rule, promotions: promotions.to_vec(), left: left.clone(), right: right.clone() })
+        };
+        match (left, right) {
+            (Packet::Int(l), Packet::Int(r)) => match l.cmp(r) {
+                Ordering::Less => decide(trace, path, promotions, Rule::Integers, "Left side is smaller, so inputs are in the right order"),
+                Ordering::Greater => decide(trace, path, promotions, Rule::Integers, "Right side is smaller, so inputs are not in the right order"),
+                Ordering::Equal => None
+            },
+            (Packet::List(l), Packet::List(r)) => {
+                for index in 0..l.len().max(r.len()) {
+                    match (l.get(index), r.get(index)) {
+                        (Some(l), Some(r)) => {
+                            path.push(index);
+                            if let Some(decision) = step(l, r, depth + 1, path, promotions, trace) {
+                                return Some(decision);
+                            }
+                            path.pop();
+                        },
+                        (None, _) => return decide(trace, path, promotions, Rule::ListLength, "Left side ran out of items, so inputs are in the right order"),
+                        (_, None) => return decide(trace, path, promotions, Rule::ListLength, "Right side ran out of items, so inputs are not in the right order")
+                    }
+                }
+                None
+            },
+            (Packet::Int(_), Packet::List(_)) => {
+                let list = Packet::List(vec![left.clone()]);
+                trace.push(format!("{}  - Mixed types; convert left to {} and retry comparison", indent, list));
+                promotions.push(Promotion { level: path.len(), side: Side::Left });
+                let decision = step(&list, right, depth + 1, path, promotions, trace);
+                promotions.pop();
+                decision
+            },
+            (Packet::List(_), Packet::Int(_)) => {
+                let list = Packet::List(vec![right.clone()]);
+                trace.push(format!("{}  - Mixed types; convert right to {} and retry comparison", indent, list));
+                promotions.push(Promotion { level: path.len(), side: Side::Right });
+                let decision = step(left, &list, depth + 1, path, promotions, trace);
+                promotions.pop();
+                decision
+            }
+        }
+    }
+
+    let mut trace = Vec::new();
+    let decision = step(left, right, 0, &mut Vec::new(), &mut Vec::new(), &mut trace);
+    let ordering = match &decision {
+        None => Ordering::Equal,
+        Some(decision) => decision.left.cmp(&decision.right)
+    };
+
+    Explanation { ordering, decision, trace }
+}
+
+/// Packets are JSON arrays of integers and further arrays
+impl From<&Packet> for Json {
+    fn from(packet: &Packet) -> Self {
+        match packet {
+            Packet::Int(value) => Json::Integer(*value),
+            Packet::List(items) => Json::Array(items.iter().map(Json::from).collect())
+        }
+    }
+}
+
+/// Reason JSON could not be turned into packets
+#[derive(Debug, Clone, PartialEq)]
+pub enum PacketJsonError {
+    /// The text is not valid JSON
+    Json(ParseError),
+    /// The outermost value should be an array of packets, but is `value`
+    NotArray(Json),
+    /// `value` is neither an integer nor an array; `path` holds the index taken into each array to get to it
+    NotPacket { path: Vec<usize>, value: Json }
+}
+
+impl fmt::Display for PacketJsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PacketJsonError::Json(e) => write!(f, "{}", e),
+            PacketJsonError::NotArray(value) => write!(f, "expected an array of packets, found {}", value),
+            PacketJsonError::NotPacket { path, value } => {
+                write!(f, "expected an integer or an array at $")?;
+                for index in path {
+                    write!(f, "[{}]", index)?;
+                }
+                write!(f, ", found {}", value)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PacketJsonError {}
+
+impl From<ParseError> for PacketJsonError {
+    fn from(e: ParseError) -> Self {
+        PacketJsonError::Json(e)
+    }
+}
+
+/// Fails on anything other than integers and arrays, saying where it was as a path such as `$[1][0]`
+impl TryFrom<&Json> for Packet {
+    type Error = PacketJsonError;
+
+    fn try_from(json: &Json) -> Result<Self, Self::Error> {
+        fn convert(json: &Json, path: &mut Vec<usize>) -> Result<Packet, PacketJsonError> {
+            match json {
+                Json::Integer(value) => Ok(Packet::Int(*value)),
+                Json::Array(values) => {
+                    let mut items = Vec::new();
+                    for (index, value) in values.iter().enumerate() {
+                        path.push(index);
+                        items.push(convert(value, path)?);
+                        path.pop();
+                    }
+                    Ok(Packet::List(items))
+                },
+                other => Err(PacketJsonError::NotPacket { path: path.clone(), value: other.clone() })
+            }
+        }
+
+        convert(json, &mut Vec::new())
+    }
+}
+
+/// Packets from a JSON array of them, such as one written out from [`sorted`].
+/// Paths in errors start from the outer array, so their first index is that of the packet.
+pub fn parse_json(input: &str) -> Result<Vec<Packet>, PacketJsonError> {
+    match input.parse::<Json>()? {
+        Json::Array(values) => values.iter()
+            .enumerate()
+            .map(|(index, value)| Packet::try_from(value).map_err(|e| match e {
+                PacketJsonError::NotPacket { mut path, value } => {
+                    path.insert(0, index);
+                    PacketJsonError::NotPacket { path, value }
+                },
+                e => e
+            }))
+            .collect(),
+        other => Err(PacketJsonError::NotArray(other))
+    }
+}
+
+/// Each pair of packets, in order of appearance
+pub fn parse(input: &str) -> Result<Vec<(Packet, Packet)>, ParseError> {
+    paragraphs(input)
+        .map(|(start, s)| {
+            let mut lines = s.lines();
+            let mut packet = |n: usize| -> Result<Packet, ParseError> {
+                let line = lines.next().ok_or_else(|| ParseError::missing(start + n, 1, "a packet"))?;
+                line.parse::<Packet>().map_err(|e| e.offset_lines(start + n - 1))
+            };
+            let pair = (packet(1)?, packet(2)?);
+            match lines.next() {
+                Some(line) => Err(ParseError::unexpected(start + 3, 1, line, "a blank line between pairs")),
+                None => Ok(pair)
+            }
+        })
+        .collect()
+}
+
+/// Sum of the (1-based) indices of the pairs that are already in the right order
+pub fn part_1(pairs: &[(Packet, Packet)]) -> usize {
+    pairs.iter()
+        .enumerate()
+        .filter_map(|(index, (left, right))| {
+            match left.cmp(right) {
+                Ordering::Less => Some(index + 1),
+                _ => None
+            }
+        })
+        .sum::<usize>()
+}
+
+/// Sorts all packets along with the two divider packets and multiplies the divider packets' (1-based) indices
+pub fn part_2(pairs: &[(Packet, Packet)]) -> usize {
+    // Marked so that they can be found again, and placed after any packets equal to them
+    let dividers = [Packet::List(vec![Packet::list_of(&[2])]), Packet::List(vec![Packet::list_of(&[6])])];
+    let mut packets = pairs.iter()
+        .flat_map(|(left, right)| [(left.clone(), false), (right.clone(), false)])
+        .chain(dividers.into_iter().map(|divider| (divider, true)))
+        .collect::<Vec<(Packet, bool)>>();
+    packets.sort();
+    packets.iter().enumerate().filter_map(|(index, (_, divider))| {
+        match divider {
+            true => Some(index + 1),
+            false => None
+        }
+    })
+    .product::<usize>()
+}
+
+/// Every packet of every pair, in the right order
+pub fn sorted(pairs: &[(Packet, Packet)]) -> Vec<Packet> {
+    let mut packets = pairs.iter().flat_map(|(left, right)| [left.clone(), right.clone()]).collect::<Vec<Packet>>();
+    packets.sort();
+    packets
+}
+
+pub struct Day13;
+
+impl Solver for Day13 {
+    const DAY: u8 = 13;
+    type Input = Vec<(Packet, Packet)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(&self, pairs: &Self::Input) -> Answer {
+        part_1(pairs).into()
+    }
+
+    fn part_2(&self, pairs: &Self::Input) -> Answer {
+        part_2(pairs).into()
+    }
+}
+
+/// Example pairs of packets from the puzzle description
+pub const EXAMPLE: &str =
+"[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+
+[9]
+[[8,7,6]]
+
+[[4,4],4,4]
+[[4,4],4,4,4]
+
+[7,7,7,7]
+[7,7,7]
+
+[]
+[3]
+
+[[[]]]
+[[]]
+
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[1,[2,[3,[4,[5,6,0]]]],8,9]";
+
+#[test]
+fn day_13_part_1() {
+    let pairs = EXAMPLE;
+    let expected_results = [
+        Ordering::Less,
+        Ordering::Less,
+        Ordering::Greater,
+        Ordering::Less,
+        Ordering::Greater,
+        Ordering::Less,
+        Ordering::Greater,
+        Ordering::Greater
+    ];
+    let expected_correct_sum = 13;
+    let mut calculated_correct_sum = 0;
+    for (index, (_, pair)) in paragraphs(pairs).enumerate() {
+        let mut l = pair.lines();
+        let (left, right) = (l.next().expect("pair should have left item"), l.next().expect("pair should have right item"));
+        let result = left.parse::<Packet>().unwrap().cmp(&right.parse::<Packet>().unwrap());
+        assert_eq!(expected_results[index], result);
+        if result == Ordering::Less {
+            calculated_correct_sum += index + 1;
+        }
+    }
+
+    assert_eq!(expected_correct_sum, calculated_correct_sum);
+    assert_eq!(expected_correct_sum, part_1(&parse(pairs).unwrap()));
+}
+
+#[test]
+fn day_13_part_2() {
+    let input = EXAMPLE;
+    assert_eq!(140, part_2(&parse(input).unwrap()));
+}
+
+#[test]
+fn day_13_parse_error() {
+    assert_eq!(Err(ParseError::missing(2, 5, "']'")), parse("[1,2]\n[[3]"));
+    assert_eq!(Err(ParseError::unexpected(5, 3, "a", "a packet of integers and lists")), parse("[1]\n[2]\n\n[]\n[[a]]"));
+    assert_eq!(Err(ParseError::missing(2, 1, "a packet")), parse("[1]"));
+    assert_eq!(Err(ParseError::unexpected(3, 1, "[3]", "a blank line between pairs")), parse("[1]\n[2]\n[3]\n[4]"));
+    assert_eq!(Err(ParseError::unexpected(1, 5, ";", "',' or ']'")), "[1,2;3]".parse::<Packet>());
+    assert_eq!(Err(ParseError::unexpected(1, 5, "]", "the end of the packet")), "[[]]]".parse::<Packet>());
+    assert_eq!(Err(ParseError::unexpected(1, 1, "7", "a packet of integers and lists")), "7".parse::<Packet>());
+    assert_eq!(Err(ParseError::unexpected(1, 4, "]", "a packet of integers and lists")), "[1,]".parse::<Packet>());
+    assert_eq!(Err(ParseError::invalid_number(1, 2, "99999999999999999999")), "[99999999999999999999]".parse::<Packet>());
+}
+
+#[test]
+fn day_13_packet() {
+    for line in EXAMPLE.lines().filter(|line| !line.is_empty()) {
+        assert_eq!(line, line.parse::<Packet>().unwrap().to_string());
+    }
+    let packet = "[[1],[2,-3,[]],4]".parse::<Packet>().unwrap();
+    assert!(matches!(&packet, Packet::List(items) if items.len() == 3));
+    assert_eq!("[[1],[2,-3,[]],4]", packet.to_string());
+
+    // An integer compares as a list holding just it
+    assert_eq!(Packet::Int(3), Packet::list_of(&[3]));
+    assert_eq!(Packet::List(vec![Packet::list_of(&[3])]), Packet::Int(3));
+    // Only the text tells them apart
+    assert_ne!(Packet::Int(3).to_string(), Packet::list_of(&[3]).to_string());
+    assert!(Packet::list_of(&[3]) < Packet::list_of(&[3, 0]));
+    assert!(Packet::Int(4) > Packet::list_of(&[3, 9]));
+
+    let mut packets = ["[[2]]", "[1,1,5,1,1]", "[]", "[[1],4]", "[1,1,3,1,1]"].map(|s| s.parse::<Packet>().unwrap());
+    packets.sort();
+    assert_eq!(vec!["[]", "[1,1,3,1,1]", "[1,1,5,1,1]", "[[1],4]", "[[2]]"], packets.iter().map(Packet::to_string).collect::<Vec<String>>());
+}
+
+#[test]
+fn day_13_json() {
+    let pairs = parse(EXAMPLE).unwrap();
+    let json = Json::Array(sorted(&pairs).iter().map(Json::from).collect());
+    // Packets that only differ in nesting are equal, so compare the text
+    let text = |packets: Vec<Packet>| packets.iter().map(Packet::to_string).collect::<Vec<String>>();
+    assert_eq!(Ok(text(sorted(&pairs))), parse_json(&json.to_string()).map(text));
+    for (left, right) in &pairs {
+        assert_eq!(left.to_string(), Json::from(left).to_string());
+        assert_eq!(Ok(right.to_string()), Packet::try_from(&Json::from(right)).map(|packet| packet.to_string()));
+    }
+
+    assert_eq!(Ok(vec![String::from("[1,[]]")]), parse_json(" [ [1, [ ]] ] ").map(text));
+    let error = parse_json(r#"[[1], [2, ["a"]]]"#).unwrap_err();
+    assert_eq!(PacketJsonError::NotPacket { path: vec![1, 1, 0], value: Json::from("a") }, error);
+    assert_eq!("expected an integer or an array at $[1][1][0], found \"a\"", error.to_string());
+    assert_eq!(Err(PacketJsonError::NotPacket { path: vec![0, 0], value: Json::Float(1.5) }), parse_json("[[1.5]]"));
+    assert_eq!(Err(PacketJsonError::NotPacket { path: vec![], value: Json::Bool(true) }), Packet::try_from(&Json::Bool(true)));
+    assert_eq!(Err(PacketJsonError::NotArray(Json::Object(Vec::new()))), parse_json("{}"));
+    assert_eq!(Err(PacketJsonError::Json(ParseError::unexpected(1, 3, ")", "a JSON value"))), parse_json("[[)"));
+}
+
+#[test]
+fn day_13_explain() {
+    let pairs = parse(EXAMPLE).unwrap();
+    for (left, right) in &pairs {
+        assert_eq!(left.cmp(right), explain(left, right).ordering);
+        assert_eq!(right.cmp(left), explain(right, left).ordering);
+    }
+
+    let explanation = explain(&pairs[2].0, &pairs[2].1);
+    assert_eq!(
+"- Compare [9] vs [[8,7,6]]
+  - Compare 9 vs [8,7,6]
+    - Mixed types; convert left to [9] and retry comparison
+    - Compare [9] vs [8,7,6]
+      - Compare 9 vs 8
+        - Right side is smaller, so inputs are not in the right order
+", explanation.to_string());
+    let promotions = vec![Promotion { level: 1, side: Side::Left }];
+    assert_eq!(Some(Decision { path: vec![0, 0], rule: Rule::Integers, promotions, left: Packet::Int(9), right: Packet::Int(8) }), explanation.decision);
+
+    let explanation = explain(&pairs[3].0, &pairs[3].1);
+    assert_eq!(Some((vec![], Rule::ListLength, vec![])), explanation.decision.as_ref().map(|d| (d.path.clone(), d.rule, d.promotions.clone())));
+    assert_eq!("  - Left side ran out of items, so inputs are in the right order", explanation.trace.last().unwrap());
+
+    let explanation = explain(&pairs[7].0, &pairs[7].1);
+    assert_eq!(Some(vec![1, 1, 1, 1, 2]), explanation.decision.map(|d| d.path));
+
+    // Promoted on the right, only after a level that didn't need it, and decided on the new list itself
+    let explanation = explain(&Packet::List(vec![Packet::list_of(&[3]), Packet::list_of(&[4, 5])]), &Packet::List(vec![Packet::list_of(&[3]), Packet::Int(4)]));
+    let decision = explanation.decision.unwrap();
+    assert_eq!((vec![1], Rule::ListLength, vec![Promotion { level: 1, side: Side::Right }]), (decision.path, decision.rule, decision.promotions));
+
+    let explanation = explain(&Packet::list_of(&[1, 2]), &Packet::List(vec![Packet::Int(1), Packet::list_of(&[2])]));
+    assert_eq!((Ordering::Equal, None), (explanation.ordering, explanation.decision));
+    assert_eq!(6, explanation.trace.len());
+}
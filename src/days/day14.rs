@@ -0,0 +1,96 @@
+use crate::error::{ParseError, parse_lines};
+use crate::sand_stuff::*;
+use crate::solver::{Answer, Solver};
+
+/// Each line is a path of rock
+pub fn parse(input: &str) -> Result<Vec<DrawInstruction>, ParseError> {
+    let instructions = parse_lines::<DrawInstruction>(input)?;
+    if instructions.is_empty() {
+        return Err(ParseError::missing(1, 1, "a path of rock"));
+    }
+
+    Ok(instructions)
+}
+
+/// Drops sand until a particle falls into the abyss below the lowest rock
+pub fn fill_until_abyss(instructions: &[DrawInstruction]) -> SandCave {
+    let mut cave = SandCave::new(instructions, (500, 0));
+    while cave.drop_sand().is_some() {}
+
+    cave
+}
+
+/// Adds a floor two units below the lowest rock and drops sand until the source is plugged
+pub fn fill_until_plugged(instructions: &[DrawInstruction]) -> SandCave {
+    let mut cave = SandCave::new(instructions, (500, 0)).with_floor(2);
+    while cave.drop_sand().is_some() {}
+
+    cave
+}
+
+/// Number of sand particles at rest before sand starts falling into the abyss
+pub fn part_1(instructions: &[DrawInstruction]) -> usize {
+    fill_until_abyss(instructions).count_material(Material::Sand)
+}
+
+/// Number of sand particles at rest once the source is plugged
+pub fn part_2(instructions: &[DrawInstruction]) -> usize {
+    fill_until_plugged(instructions).count_material(Material::Sand)
+}
+
+pub struct Day14;
+
+impl Solver for Day14 {
+    const DAY: u8 = 14;
+    type Input = Vec<DrawInstruction>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(&self, instructions: &Self::Input) -> Answer {
+        part_1(instructions).into()
+    }
+
+    fn part_2(&self, instructions: &Self::Input) -> Answer {
+        part_2(instructions).into()
+    }
+}
+
+#[test]
+fn day_14_part_1() {
+    let input = "498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9";
+    let instructions = parse(input).unwrap();
+    let mut canvas = Canvas::new(instructions, (500, 0));
+    println!("{}", canvas);
+
+    let mut should_stop = false;
+    while !should_stop {
+        canvas.add_sand();
+        while canvas.active_sand.is_some() {
+            // std::thread::sleep(std::time::Duration::from_secs_f32(0.25));
+            should_stop = canvas.update(false).is_none();
+        }
+    }
+    println!("{}", canvas);
+    assert_eq!(24, canvas.count_material(Material::Sand));
+}
+
+#[test]
+fn day_14_part_2() {
+    let input = "498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9";
+    let instructions = parse(input).unwrap();
+    let cave = fill_until_plugged(&instructions);
+    println!("{}", cave);
+
+    assert_eq!(93, cave.count_material(Material::Sand));
+    assert_eq!(24, fill_until_abyss(&instructions).count_material(Material::Sand));
+}
+
+#[test]
+fn day_14_parse_error() {
+    assert_eq!(Err(ParseError::unexpected(2, 10, "502", "a pair formatted 'x,y'")), parse("498,4 -> 498,6\n503,4 -> 502 -> 502,9").map(|_| ()));
+    assert_eq!(Err(ParseError::invalid_number(1, 5, "x")), parse("498,x -> 498,6").map(|_| ()));
+}
@@ -0,0 +1,416 @@
+use crate::point::Point;
+use crate::grid::Grid;
+use crate::error::ParseError;
+use crate::scan;
+use crate::solver::{Answer, Solver};
+use std::str::FromStr;
+use std::collections::{HashMap, HashSet};
+
+// New code
+// I don't know why I didn't just do this in the first place
+/// Structure with two points;
+/// Solution-specific implementation that assumes a slope of 1 or -1 (or 0 for zero-length lines)
+#[derive(PartialEq)]
+struct Line {
+    a: Point<i32>,
+    b: Point<i32>
+}
+
+impl Line {
+    fn new(a: Point<i32>, b: Point<i32>) -> Self {
+        Line { a, b }
+    }
+
+    fn slope(&self) -> i32 {
+        if self.a.x < self.b.x {
+            (self.b.y - self.a.y).signum()
+        } else if self.a.x > self.b.x {
+            (self.a.y - self.b.y).signum()
+        } else {
+            0
+        }
+    }
+
+    fn base(&self) -> i32 {
+        self.a.y - self.a.x * self.slope()
+    }
+
+    fn intersect(&self, other: &Line) -> Option<Point<i32>> {
+        if self.slope() == other.slope() { return None; }
+
+        let x = (self.base() - other.base()) / 2;
+        let y = self.slope() * x + self.base();
+        
+        if x > self.a.x.max(self.b.x) || x < self.a.x.min(self.b.x)
+        || y > self.a.y.max(self.b.y) || y < self.a.y.min(self.b.y) {
+            return None;
+        }
+        
+
+        Some(Point::new(x, y))
+    }
+}
+// End of new code
+
+#[allow(unused)]
+fn get_points_on_distance_within_bounds(p: Point<i32>, distance: i32, min: i32, max: i32) -> HashSet<Point<i32>> {
+    let r = min..=max;
+    let mut points = HashSet::new();
+    for y in p.y - distance..=p.y + distance {
+        if !r.contains(&y) { continue; }
+        let d = distance - (p.y - y).abs();
+        if r.contains(&(p.x - d)) {
+            points.insert(Point::new(p.x - d, y));
+        }
+        if r.contains(&(p.x + d)) {
+            points.insert(Point::new(p.x + d, y));
+        }
+    }
+
+    points
+}
+
+#[allow(unused)]
+fn get_hash_set_intersections(sets: Vec<HashSet<Point<i32>>>) -> HashSet<Point<i32>> {
+    let mut intersections: HashSet<Point<i32>> = HashSet::new();
+    for (index, set) in sets.iter().enumerate() {
+        for other in sets.iter().skip(index + 1) {
+            intersections = &intersections | &(set & other);
+        }
+    }
+
+    intersections
+}
+
+// WHY ARE YOU SO BIG
+pub fn calc_tuning_freq(p: &Point<i32>) -> i64 {
+    static MUL: i64 = 4000000;
+    p.x as i64 * MUL + p.y as i64
+}
+
+// Used for day_15_part_2_output_downscaled
+#[allow(unused)]
+fn div_rounded(a: i32, b: i32) -> i32 {
+    let d = a / b;
+    let q = (a % b) as f32;
+    if q / b as f32 > 0.5 {
+        d + 1
+    } else {
+        d
+    }
+}
+
+/// The `Point` value of `Sensor` represents the position of its closest `Beacon`
+#[allow(unused)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tile {
+    Sensor(Point<i32>),
+    Beacon,
+    Empty,
+    Unknown
+}
+
+impl From<Tile> for char {
+    fn from(value: Tile) -> Self {
+        match value {
+            Tile::Sensor(_) => 'S',
+            Tile::Beacon => 'B',
+            Tile::Empty => '#',
+            Tile::Unknown => '.',
+        }
+    }
+}
+
+impl std::fmt::Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", char::from(*self))
+    }
+}
+
+pub struct CaveMap {
+    /// Corners of the area covered by the sensors
+    pub min: Point<i32>,
+    pub max: Point<i32>,
+    /// Positions of sensors and beacons
+    pub points: HashMap<Point<i32>, Tile>,
+    /// Value is the manhattan distance from its beacon
+    pub signals: HashMap<Point<i32>, i32>
+}
+
+/// Parses lines of `Sensor at x=.., y=..: closest beacon is at x=.., y=..`
+impl FromStr for CaveMap {
+    type Err = ParseError;
+
+    fn from_str(inputs: &str) -> Result<Self, Self::Err> {
+        let mut points = HashMap::new();
+        let mut signals = HashMap::new();
+        let mut min_x = i32::MAX;
+        let mut max_x = i32::MIN;
+        let mut min_y = i32::MAX;
+        let mut max_y = i32::MIN;
+    
+        for (index, line) in inputs.lines().enumerate() {
+            let (sensor_x, sensor_y, beacon_x, beacon_y) = scan!(line, SENSOR_PATTERN, i32, i32, i32, i32).map_err(|e| e.offset_lines(index))?;
+            let sensor = Point::new(sensor_x, sensor_y);
+            let beacon = Point::new(beacon_x, beacon_y);
+            let distance = sensor.manhattan_distance(&beacon);
+    
+            min_x = min_x.min(sensor.x - distance);
+            max_x = max_x.max(sensor.x + distance);
+            min_y = min_y.min(sensor.y - distance);
+            max_y = max_y.max(sensor.y + distance);
+            
+            points.insert(sensor, Tile::Sensor(beacon));
+            points.insert(beacon, Tile::Beacon);
+            signals.insert(sensor, distance);
+        }
+        if signals.is_empty() {
+            return Err(ParseError::missing(1, 1, "a sensor"));
+        }
+    
+        Ok(CaveMap { min: Point::new(min_x, min_y), max: Point::new(max_x, max_y), points, signals })
+    }
+}
+
+impl CaveMap {
+    // Exists to show how I originally attempted to solve part two
+    pub fn get_unknown_in_range(&self, min: Point<i32>, max: Point<i32>) -> Option<Point<i32>> {
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                let point = Point::new(x, y);
+                if self.is_point_unknown(point) {
+                    return Some(point);
+                }
+            }
+        }
+        None
+    }
+
+    /// As optimized as I can get it with my knowledge anyways.
+    /// On my machine, part two takes my machine about 40~50 seconds
+    /// to process on **RELEASE** build.
+    /// 
+    /// Additionally, I have not ensured that this always works,
+    /// I'm too dumb to figure that out.
+    pub fn get_unknown_in_range_optimized(&self, min: i32, max: i32) -> Option<Point<i32>> {
+        let mut borders = Vec::new();
+        for (&signal, &distance) in self.signals.iter() {
+            borders.push(get_points_on_distance_within_bounds(signal, distance, min, max));
+        }
+        let intersections = get_hash_set_intersections(borders);
+
+        for point in intersections.iter() {
+            let p = Point::new(point.x - 1, point.y);
+            if self.is_point_unknown(p) { return Some(p); }
+            let p = Point::new(point.x + 1, point.y);
+            if self.is_point_unknown(p) { return Some(p); }
+        }
+
+        None
+    }
+
+    /// Full disclosure, this is written after I looked at the AoC subreddit,
+    /// curious about how others solved the problem. In hindsight I feel super
+    /// dumb not taking this approach and doing it how I did it before,
+    /// but at least I know now.
+    pub fn get_unknown_in_range_optimized_more(&self, min: i32, max: i32) -> Option<Point<i32>> {
+        let (corner_a, corner_b) = (Point::new(min, min), Point::new(max, max));
+        for (&signal, &distance) in self.signals.iter() {
+            let top_to_left = Line::new(Point::new(signal.x, signal.y + distance), Point::new(signal.x + distance, signal.y));
+            let left_to_bottom = Line::new(Point::new(signal.x + distance, signal.y), Point::new(signal.x, signal.y - distance));
+            for (&other_s, &other_d) in self.signals.iter() {
+                if signal == other_s { continue; }
+                let top_to_right = Line::new(Point::new(other_s.x, other_s.y + other_d), Point::new(other_s.x - distance, other_s.y));
+                let right_to_bottom = Line::new(Point::new(other_s.x - distance, other_s.y), Point::new(other_s.x, other_s.y - distance));
+                if let Some(intersect) = top_to_left.intersect(&top_to_right) {
+                    let potential = intersect.add_tuple((0, 1));
+                    if potential.in_range(corner_a, corner_b) && self.is_point_unknown(potential) { return Some(potential); }
+                    let potential = intersect.add_tuple((0, -1));
+                    if potential.in_range(corner_a, corner_b) && self.is_point_unknown(potential) { return Some(potential); }
+                }
+                if let Some(intersect) = left_to_bottom.intersect(&right_to_bottom) {
+                    let potential = intersect.add_tuple((0, 1));
+                    if potential.in_range(corner_a, corner_b) && self.is_point_unknown(potential) { return Some(potential); }
+                    let potential = intersect.add_tuple((0, -1));
+                    if potential.in_range(corner_a, corner_b) && self.is_point_unknown(potential) { return Some(potential); }
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Positions on the given row that cannot contain a beacon
+    pub fn get_empty_in_row(&self, row_number: i32) -> Vec<Point<i32>> {
+        let mut empty = Vec::new();
+        'range: for x in self.min.x..=self.max.x {
+            let point = Point::from((x, row_number));
+            if self.points.contains_key(&point) { continue; }
+            for (&signal_pos, &distance) in self.signals.iter() {
+                if point.manhattan_distance(&signal_pos) <= distance {
+                    empty.push(point);
+                    continue 'range;
+                }
+            }
+        }
+
+        empty
+    }
+
+    /// Whether `point` is out of range of every sensor
+    pub fn is_point_unknown(&self, point: Point<i32>) -> bool {
+        if self.points.contains_key(&point) { return false; }
+        for (&signal, &distance) in self.signals.iter() {
+            if point.manhattan_distance(&signal) <= distance {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Tiles from `min` to `max` inclusive, where positions in range of a sensor are `Empty`.
+    /// `min` is at `(0, 0)` in the returned grid.
+    pub fn render(&self, min: Point<i32>, max: Point<i32>) -> Grid<Tile> {
+        let width = (max.x - min.x + 1).max(0) as usize;
+        let height = (max.y - min.y + 1).max(0) as usize;
+        Grid::from_fn(width, height, |offset| {
+            let point = min + offset;
+            match self.points.get(&point) {
+                Some(&tile) => tile,
+                None if self.is_point_unknown(point) => Tile::Unknown,
+                None => Tile::Empty
+            }
+        })
+    }
+}
+
+/// Layout of each line of the input, for [`scan!`]
+const SENSOR_PATTERN: &str = "Sensor at x={}, y={}: closest beacon is at x={}, y={}";
+
+pub fn parse(input: &str) -> Result<CaveMap, ParseError> {
+    input.parse()
+}
+
+/// Number of positions on row `row_number` that cannot contain a beacon
+pub fn part_1(map: &CaveMap, row_number: i32) -> usize {
+    map.get_empty_in_row(row_number).len()
+}
+
+/// Tuning frequency of the only position within `0..=maximum` that could contain the distress beacon
+pub fn part_2(map: &CaveMap, maximum: i32) -> i64 {
+    // let unknown = map.get_unknown_in_range(Point::new(0, 0), Point::new(maximum, maximum)).unwrap();
+    // let unknown = map.get_unknown_in_range_optimized(0, maximum).unwrap();
+    let unknown = map.get_unknown_in_range_optimized_more(0, maximum).unwrap();
+
+    calc_tuning_freq(&unknown)
+}
+
+pub struct Day15;
+
+impl Solver for Day15 {
+    const DAY: u8 = 15;
+    type Input = CaveMap;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(&self, map: &Self::Input) -> Answer {
+        part_1(map, 2000000).into()
+    }
+
+    fn part_2(&self, map: &Self::Input) -> Answer {
+        part_2(map, 4000000).into()
+    }
+}
+
+/// Example sensor report from the puzzle description
+pub const EXAMPLE: &str =
+"Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3";
+
+#[test]
+fn day_15_scan_test() {
+    let example = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15";
+    assert_eq!(Ok(vec!["2", "18", "-2", "15"]), scan::fields(example, SENSOR_PATTERN));
+    assert_eq!(Ok((2, 18, -2, 15)), scan!(example, SENSOR_PATTERN, i32, i32, i32, i32));
+}
+
+#[test]
+fn day_15_part_1() {
+    let inputs = EXAMPLE;
+    let row_number = 10;
+    let map = parse(inputs).unwrap();
+    println!("{}", map.render(map.min, map.max));
+    assert_eq!(26, part_1(&map, row_number));
+}
+
+#[test]
+fn day_15_part_2() {
+    let inputs = EXAMPLE;
+    let map = parse(inputs).unwrap();
+    let rendered = map.render(Point::new(0, 0), Point::new(20, 20));
+    println!("{}", rendered);
+    assert_eq!(1, rendered.values().filter(|&&t| t == Tile::Unknown).count());
+    assert_eq!(Tile::Unknown, rendered[Point::new(14, 11)]);
+
+    // let unknown = map.get_unknown_in_range(Point::new(0, 0), Point::new(20, 20)).unwrap();
+    // let unknown = map.get_unknown_in_range_optimized(0, 20).unwrap();
+    let unknown = map.get_unknown_in_range_optimized_more(0, 20).unwrap();
+
+    assert_eq!(Point::new(14, 11), unknown);
+    assert_eq!(56000011, calc_tuning_freq(&unknown));
+    assert_eq!(56000011, part_2(&map, 20));
+}
+
+// I was REALLY struggling with part two man.
+#[test]
+fn day_15_part_2_output_downscaled() {
+    let scaling_factor = 10000;
+    let inputs = match crate::inputs::read_day(15) {
+        Ok(inputs) => inputs,
+        Err(e) => {
+            println!("skipping, the real input is needed: {}", e);
+            return;
+        }
+    };
+    let modified = inputs.lines()
+        .map(|line| {
+            let (sensor_x, sensor_y, beacon_x, beacon_y) = scan!(line, SENSOR_PATTERN, i32, i32, i32, i32).unwrap();
+            format!(
+                "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+                sensor_x / scaling_factor,
+                sensor_y / scaling_factor,
+                beacon_x / scaling_factor,
+                beacon_y / scaling_factor,
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    let map = parse(&modified).unwrap();
+    println!("{}", map.render(map.min, map.max));
+}
+
+#[test]
+fn day_15_parse_error() {
+    let input = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16 closest beacon is at x=10, y=16";
+    assert_eq!(Err(ParseError::unexpected(2, 21, "closest", "': closest beacon is at x='")), parse(input).map(|_| ()));
+    assert_eq!(Err(ParseError::unexpected(1, 1, "Beacon", "'Sensor at x='")), parse("Beacon at x=2, y=18: closest beacon is at x=-2, y=15").map(|_| ()));
+    assert_eq!(Err(ParseError::invalid_number(1, 45, "-20000000000")), parse("Sensor at x=2, y=18: closest beacon is at x=-20000000000, y=15").map(|_| ()));
+    assert_eq!(Err(ParseError::missing(1, 1, "a sensor")), parse("").map(|_| ()));
+}
@@ -0,0 +1,218 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+use crate::error::{ParseError, column_of, parse_lines, parse_number};
+use crate::graph::{Graph, distances};
+use crate::scan;
+use crate::schedule::{self, Schedule};
+use crate::solver::{Answer, Solver};
+
+/// Layout of each line of the input, for [`scan::fields`]
+const VALVE_PATTERN: &str = "Valve {} has flow rate={}; {}";
+/// Ways of introducing the list of tunnels, depending on how many there are
+const TUNNEL_PREFIXES: [&str; 2] = ["tunnels lead to valves ", "tunnel leads to valve "];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Valve {
+    pub label: String,
+    /// Pressure released per minute once opened
+    pub rate: u32,
+    /// Labels of the valves this one has tunnels to
+    pub tunnels: Vec<String>
+}
+
+/// Parses a line such as `Valve AA has flow rate=0; tunnels lead to valves DD, II, BB`
+impl FromStr for Valve {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields = scan::fields(s, VALVE_PATTERN)?;
+        let rate = parse_number(s, fields[1])?;
+        let others = TUNNEL_PREFIXES.iter()
+            .find_map(|prefix| fields[2].strip_prefix(prefix))
+            .ok_or_else(|| ParseError::unexpected(1, column_of(s, fields[2]), fields[2], "'tunnels lead to valves' or 'tunnel leads to valve'"))?;
+        let tunnels = others.split(',').map(|label| label.trim().to_string()).collect();
+
+        Ok(Valve { label: fields[0].to_string(), rate, tunnels })
+    }
+}
+
+/// Label of the valve both actors start at
+pub const START: &str = "AA";
+
+/// Valves and the tunnels between them, where valves are referred to by their index in `valves`
+pub struct ValveNetwork {
+    pub valves: Vec<Valve>,
+    /// Indexes of the valves each valve has tunnels to
+    pub tunnels: Vec<Vec<usize>>,
+    pub start: usize,
+    /// Indexes of the valves with a non-zero flow rate, the only ones worth opening.
+    /// Bit `i` of an opened set refers to `flowing[i]`.
+    pub flowing: Vec<usize>,
+    /// Fewest minutes needed to walk from one valve to another, `u32::MAX` if there is no way there
+    pub distances: Vec<Vec<u32>>
+}
+
+/// Most valves with a non-zero flow rate that fit in an opened set
+pub const MAX_FLOWING: usize = schedule::MAX_SITES;
+
+impl ValveNetwork {
+    /// # Panics
+    /// Panics if a tunnel leads to a valve that does not exist, there is no valve labelled [`START`],
+    /// or there are more than [`MAX_FLOWING`] valves with a non-zero flow rate
+    pub fn new(valves: Vec<Valve>) -> Self {
+        let indexes = valves.iter().enumerate().map(|(index, valve)| (valve.label.as_str(), index)).collect::<HashMap<&str, usize>>();
+        let tunnels = valves.iter()
+            .map(|valve| valve.tunnels.iter().map(|label| indexes[label.as_str()]).collect())
+            .collect();
+        let start = indexes[START];
+        let flowing = (0..valves.len()).filter(|&index| valves[index].rate > 0).collect::<Vec<usize>>();
+        assert!(flowing.len() <= MAX_FLOWING, "there should be at most {} valves with a non-zero flow rate", MAX_FLOWING);
+
+        let mut network = ValveNetwork { valves, tunnels, start, flowing, distances: Vec::new() };
+        network.distances = (0..network.valves.len())
+            .map(|from| {
+                let costs = distances(&network, from);
+                (0..network.valves.len()).map(|to| costs.get(&to).map_or(u32::MAX, |&cost| cost as u32)).collect()
+            })
+            .collect();
+
+        network
+    }
+
+    pub fn distance(&self, from: usize, to: usize) -> u32 {
+        self.distances[from][to]
+    }
+}
+
+/// Valves are nodes, and every tunnel takes a minute to walk through
+impl Graph for ValveNetwork {
+    type Node = usize;
+
+    fn neighbors(&self, node: usize) -> Vec<usize> {
+        self.tunnels[node].clone()
+    }
+}
+
+/// Sites are the valves with a non-zero flow rate, and time is in minutes
+impl Schedule for ValveNetwork {
+    fn sites(&self) -> usize {
+        self.flowing.len()
+    }
+
+    fn cost(&self, from: Option<usize>, to: usize) -> Option<u32> {
+        let from = from.map_or(self.start, |site| self.flowing[site]);
+        match self.distance(from, self.flowing[to]) {
+            u32::MAX => None,
+            // A minute to open the valve once there
+            distance => Some(distance + 1)
+        }
+    }
+
+    fn value(&self, site: usize, time_left: u32) -> u64 {
+        self.valves[self.flowing[site]].rate as u64 * time_left as u64
+    }
+}
+
+/// Every valve, checking that labels are unique and the tunnels lead to valves that exist
+pub fn parse(input: &str) -> Result<ValveNetwork, ParseError> {
+    let valves = parse_lines::<Valve>(input)?;
+    for (index, (line, valve)) in input.lines().zip(valves.iter()).enumerate() {
+        if valves[..index].iter().any(|v| v.label == valve.label) {
+            let label = scan::fields(line, VALVE_PATTERN)?[0];
+            return Err(ParseError::unexpected(index + 1, column_of(line, label), label, "a label not used by an earlier valve"));
+        }
+        if let Some(label) = valve.tunnels.iter().find(|&label| !valves.iter().any(|v| &v.label == label)) {
+            let others = &line[line.find(';').unwrap()..];
+            let text = others.find(label.as_str()).map_or(others, |i| &others[i..i + label.len()]);
+            return Err(ParseError::unexpected(index + 1, column_of(line, text), label, "the label of a valve"));
+        }
+        if valve.rate > 0 && valves[..=index].iter().filter(|v| v.rate > 0).count() > MAX_FLOWING {
+            return Err(ParseError::unexpected(index + 1, 1, line, &format!("at most {} valves with a non-zero flow rate", MAX_FLOWING)));
+        }
+    }
+    if !valves.iter().any(|valve| valve.label == START) {
+        return Err(ParseError::missing(input.lines().count().max(1), 1, &format!("a valve labelled {}", START)));
+    }
+
+    Ok(ValveNetwork::new(valves))
+}
+
+/// Most pressure that can be released in 30 minutes
+pub fn part_1(network: &ValveNetwork) -> u64 {
+    schedule::best(network, 30)
+}
+
+/// Most pressure that can be released in 26 minutes, with an elephant helping
+pub fn part_2(network: &ValveNetwork) -> u64 {
+    schedule::best_disjoint_pair(&schedule::best_per_set(network, 26))
+}
+
+pub struct Day16;
+
+impl Solver for Day16 {
+    const DAY: u8 = 16;
+    type Input = ValveNetwork;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(&self, network: &Self::Input) -> Answer {
+        part_1(network).into()
+    }
+
+    fn part_2(&self, network: &Self::Input) -> Answer {
+        part_2(network).into()
+    }
+}
+
+/// Example scan of the valves from the puzzle description
+pub const EXAMPLE: &str =
+"Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+Valve BB has flow rate=13; tunnels lead to valves CC, AA
+Valve CC has flow rate=2; tunnels lead to valves DD, BB
+Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
+Valve EE has flow rate=3; tunnels lead to valves FF, DD
+Valve FF has flow rate=0; tunnels lead to valves EE, GG
+Valve GG has flow rate=0; tunnels lead to valves FF, HH
+Valve HH has flow rate=22; tunnel leads to valve GG
+Valve II has flow rate=0; tunnels lead to valves AA, JJ
+Valve JJ has flow rate=21; tunnel leads to valve II";
+
+#[test]
+fn day_16_scan() {
+    let example = "Valve AA has flow rate=0; tunnels lead to valves DD, II, BB";
+    assert_eq!(Ok(vec!["AA", "0", "tunnels lead to valves DD, II, BB"]), scan::fields(example, VALVE_PATTERN));
+    let valve = example.parse::<Valve>().unwrap();
+    assert_eq!(("AA", 0, vec!["DD", "II", "BB"]), (valve.label.as_str(), valve.rate, valve.tunnels.iter().map(String::as_str).collect::<Vec<&str>>()));
+
+    let example = "Valve HH has flow rate=22; tunnel leads to valve GG";
+    assert_eq!(Ok(vec!["HH", "22", "tunnel leads to valve GG"]), scan::fields(example, VALVE_PATTERN));
+    let valve = example.parse::<Valve>().unwrap();
+    assert_eq!(("HH", 22, vec!["GG"]), (valve.label.as_str(), valve.rate, valve.tunnels.iter().map(String::as_str).collect::<Vec<&str>>()));
+
+    assert_eq!(Err(ParseError::unexpected(1, 28, "tunnels leads to valve GG", "'tunnels lead to valves' or 'tunnel leads to valve'")), "Valve HH has flow rate=22; tunnels leads to valve GG".parse::<Valve>());
+}
+
+#[test]
+fn day_16_part_1() {
+    let network = parse(EXAMPLE).unwrap();
+    assert_eq!(6, network.flowing.len());
+    // AA to HH goes through DD, EE, FF and GG
+    assert_eq!(5, network.distance(network.start, 7));
+    assert_eq!(1651, part_1(&network));
+}
+
+#[test]
+fn day_16_part_2() {
+    let network = parse(EXAMPLE).unwrap();
+    assert_eq!(1707, part_2(&network));
+}
+
+#[test]
+fn day_16_parse_error() {
+    assert_eq!(Err(ParseError::unexpected(2, 50, "ZZ", "the label of a valve")), parse("Valve AA has flow rate=0; tunnels lead to valves BB\nValve BB has flow rate=1; tunnels lead to valves ZZ").map(|_| ()));
+    assert_eq!(Err(ParseError::invalid_number(1, 24, "-1")), parse("Valve AA has flow rate=-1; tunnel leads to valve AA").map(|_| ()));
+    assert_eq!(Err(ParseError::missing(1, 1, "a valve labelled AA")), parse("Valve BB has flow rate=1; tunnel leads to valve BB").map(|_| ()));
+    assert_eq!(Err(ParseError::unexpected(3, 7, "AA", "a label not used by an earlier valve")), parse("Valve AA has flow rate=0; tunnel leads to valve BB\nValve BB has flow rate=1; tunnel leads to valve AA\nValve AA has flow rate=2; tunnel leads to valve BB").map(|_| ()));
+}
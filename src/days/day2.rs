@@ -0,0 +1,232 @@
+use std::str::FromStr;
+use crate::error::{ParseError, parse_lines};
+use crate::solver::{Answer, Solver};
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Hand {
+    Rock,
+    Paper,
+    Scissors
+}
+
+/// Second column of the strategy guide, whose meaning changes between parts
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Column {
+    X,
+    Y,
+    Z
+}
+
+impl TryFrom<char> for Hand {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'A' => Ok(Hand::Rock),
+            'B' => Ok(Hand::Paper),
+            'C' => Ok(Hand::Scissors),
+            _ => Err(ParseError::unexpected(1, 1, &c.to_string(), "a hand, one of A, B or C"))
+        }
+    }
+}
+
+impl TryFrom<char> for Column {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'X' => Ok(Column::X),
+            'Y' => Ok(Column::Y),
+            'Z' => Ok(Column::Z),
+            _ => Err(ParseError::unexpected(1, 1, &c.to_string(), "one of X, Y or Z"))
+        }
+    }
+}
+
+/// Part 1's interpretation of the second column
+impl From<Column> for Hand {
+    fn from(value: Column) -> Self {
+        match value {
+            Column::X => Hand::Rock,
+            Column::Y => Hand::Paper,
+            Column::Z => Hand::Scissors
+        }
+    }
+}
+
+impl<'a> Hand {
+
+    /// The hand that this hand beats
+    pub fn get_effective(&self) -> Self {
+        match self {
+            Hand::Rock => Hand::Scissors,
+            Hand::Paper => Hand::Rock,
+            Hand::Scissors => Hand::Paper
+        }
+    }
+
+    /// The hand that beats this hand
+    pub fn get_weakness(&self) -> Self {
+        match self {
+            Hand::Rock => Hand::Paper,
+            Hand::Paper => Hand::Scissors,
+            Hand::Scissors => Hand::Rock
+        }
+    }
+
+    /// Score of the round where `instruction` is X to lose, Y to draw and Z to win against `opponent`
+    pub fn value_instruction(opponent: &Self, instruction: Column) -> i32 {
+        let you = match instruction {
+            Column::X => opponent.get_effective(),
+            Column::Y => opponent.clone(),
+            Column::Z => opponent.get_weakness()
+        };
+
+        Hand::evaluate(opponent, &you)
+    }
+
+    pub fn get_value(&self) -> i32 {
+        match self {
+            Hand::Rock => 1,
+            Hand::Paper => 2,
+            Hand::Scissors => 3
+        }
+    }
+
+    pub fn winner(left: &'a Self, right: &'a Self) -> Option<&'a Self> {
+        match (left, right) {
+            (Hand::Rock, Hand::Paper) | (Hand::Paper, Hand::Scissors) | (Hand::Scissors, Hand::Rock) => {
+                Some(right)
+            }
+            (Hand::Rock, Hand::Scissors) | (Hand::Paper, Hand::Rock) | (Hand::Scissors, Hand::Paper) => {
+                Some(left)
+            }
+            (_, _) => None
+        }
+    }
+
+    /// Score of the round from the perspective of `you`
+    pub fn evaluate(opponent: &Self, you: &Self) -> i32 {
+        let outcome_bonus = match Hand::winner(opponent, you) {
+            None => 3,
+            Some(winner) if winner == you => 6,
+            Some(_) => 0
+        };
+
+        outcome_bonus + you.get_value()
+    }
+}
+
+/// A line of the strategy guide
+#[derive(Debug, PartialEq, Clone)]
+pub struct Round {
+    pub opponent: Hand,
+    pub column: Column
+}
+
+impl FromStr for Round {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let (left, right) = line.split_once(' ')
+            .ok_or_else(|| ParseError::missing(1, line.chars().count() + 1, "a space separating the two columns"))?;
+        let width = left.chars().count();
+        let opponent = match left.chars().collect::<Vec<char>>()[..] {
+            [c] => Hand::try_from(c)?,
+            _ => return Err(ParseError::unexpected(1, 1, left, "a single character"))
+        };
+        let column = match right.chars().collect::<Vec<char>>()[..] {
+            [c] => Column::try_from(c).map_err(|e| e.offset_columns(width + 1))?,
+            _ => return Err(ParseError::unexpected(1, width + 2, right, "a single character"))
+        };
+
+        Ok(Round { opponent, column })
+    }
+}
+
+pub fn parse_line_to_score(line: &str) -> Result<i32, ParseError> {
+    let round = line.parse::<Round>()?;
+    Ok(Hand::evaluate(&round.opponent, &round.column.into()))
+}
+
+pub fn parse_line_to_score_updated(line: &str) -> Result<i32, ParseError> {
+    let round = line.parse::<Round>()?;
+    Ok(Hand::value_instruction(&round.opponent, round.column))
+}
+
+pub fn parse(input: &str) -> Result<Vec<Round>, ParseError> {
+    parse_lines(input)
+}
+
+/// Total score when the second column is assumed to be the hand to play
+pub fn part_1(rounds: &[Round]) -> i32 {
+    rounds.iter().map(|round| Hand::evaluate(&round.opponent, &round.column.into())).sum()
+}
+
+/// Total score when the second column is the outcome the round should have
+pub fn part_2(rounds: &[Round]) -> i32 {
+    rounds.iter().map(|round| Hand::value_instruction(&round.opponent, round.column)).sum()
+}
+
+pub struct Day2;
+
+impl Solver for Day2 {
+    const DAY: u8 = 2;
+    type Input = Vec<Round>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(&self, rounds: &Self::Input) -> Answer {
+        part_1(rounds).into()
+    }
+
+    fn part_2(&self, rounds: &Self::Input) -> Answer {
+        part_2(rounds).into()
+    }
+}
+
+#[test]
+fn day_2_part_1() {
+    let input = "A Y
+B X
+C Z";
+    const EXPECTED_SCORES: [i32; 3] = [8, 1, 6];
+    let mut total = 0;
+    for (index, line) in input.lines().enumerate() {
+        let score = parse_line_to_score(line).unwrap();
+        assert_eq!(EXPECTED_SCORES[index], score);
+        total += score;
+    }
+    assert_eq!(total, EXPECTED_SCORES.into_iter().sum());
+    assert_eq!(total, part_1(&parse(input).unwrap()));
+}
+
+#[test]
+fn day_2_part_2() {
+    let input = "A Y
+B X
+C Z";
+    const EXPECTED_SCORES: [i32; 3] = [4, 1, 7];
+    let mut total = 0;
+    for (index, line) in input.lines().enumerate() {
+        let score = parse_line_to_score_updated(line).unwrap();
+        assert_eq!(EXPECTED_SCORES[index], score);
+        total += score;
+    }
+    assert_eq!(total, EXPECTED_SCORES.into_iter().sum());
+    assert_eq!(total, part_2(&parse(input).unwrap()));
+}
+
+#[test]
+fn day_2_parse_error() {
+    assert_eq!(Err(ParseError::unexpected(2, 3, "W", "one of X, Y or Z")), parse("A Y\nB W"));
+    assert_eq!(Err(ParseError::unexpected(1, 1, "D", "a hand, one of A, B or C")), parse("D Y"));
+    assert_eq!(Err(ParseError::missing(1, 3, "a space separating the two columns")), parse("AY"));
+    // Columns the wrong way around
+    assert_eq!(Err(ParseError::unexpected(1, 1, "Y", "a hand, one of A, B or C")), parse("Y A"));
+    // Columns are counted in characters rather than bytes
+    assert_eq!(Err(ParseError::missing(1, 3, "a space separating the two columns")), parse("ÀY"));
+    assert_eq!(Err(ParseError::unexpected(1, 3, "XY", "a single character")), parse("A XY"));
+}
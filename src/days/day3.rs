@@ -0,0 +1,157 @@
+use crate::error::ParseError;
+use crate::solver::{Answer, Solver};
+
+pub fn calculate_priority(c: &char) -> i32 {
+    match c {
+        'a'..='z' => *c as i32 - 96,
+        'A'..='Z' => *c as i32 - 38,
+        _ => panic!("Invalid character given")
+    }
+}
+
+/// The item type found in both compartments of a rucksack
+pub fn get_rucksack_type(sack: &str) -> char {
+    let (left, right) = sack.split_at(sack.len() / 2);
+    left.chars()
+        .find(|c| right.contains(*c))
+        .expect("Both compartments did not share a type")
+}
+
+/// The item type shared between a group of three elves
+pub fn get_badge_type(first: &str, second: &str, third: &str) -> char {
+    first.chars()
+        .filter(|c| second.contains(*c))
+        .find(|c| third.contains(*c))
+        .expect("Group of elves did not share a badge type.")
+}
+
+/// Parses a rucksack of item types, `a` to `z` and `A` to `Z`, split evenly between two compartments
+/// that share an item type
+fn parse_rucksack(line: &str) -> Result<String, ParseError> {
+    if let Some((column, c)) = line.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+        return Err(ParseError::unexpected(1, column + 1, &c.to_string(), "an item type, a letter"));
+    }
+    if line.len() % 2 != 0 {
+        return Err(ParseError::unexpected(1, 1, line, "an even number of item types, half in each compartment"));
+    }
+    let (left, right) = line.split_at(line.len() / 2);
+    if !left.chars().any(|c| right.contains(c)) {
+        return Err(ParseError::unexpected(1, left.len() + 1, right, "a compartment sharing an item type with the first"));
+    }
+
+    Ok(line.to_string())
+}
+
+/// Rucksacks are lines of item types, carried by groups of three elves that share a badge type
+pub fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+    let sacks = input.lines()
+        .enumerate()
+        .map(|(index, line)| parse_rucksack(line).map_err(|e| e.offset_lines(index)))
+        .collect::<Result<Vec<String>, ParseError>>()?;
+    if sacks.len() % 3 != 0 {
+        return Err(ParseError::missing(sacks.len() + 1, 1, "a rucksack, as elves travel in groups of three"));
+    }
+    for (index, group) in sacks.chunks_exact(3).enumerate() {
+        if !group[0].chars().any(|c| group[1].contains(c) && group[2].contains(c)) {
+            return Err(ParseError::unexpected(index * 3 + 3, 1, &group[2], "a rucksack sharing a badge type with the rest of its group"));
+        }
+    }
+
+    Ok(sacks)
+}
+
+/// Sum of the priorities of the item types shared by both compartments
+pub fn part_1(sacks: &[String]) -> i32 {
+    sacks.iter()
+        .map(|sack| calculate_priority(&get_rucksack_type(sack)))
+        .sum()
+}
+
+/// Sum of the priorities of each group's badge
+pub fn part_2(sacks: &[String]) -> i32 {
+    // Parsing checks that the elves travel in groups of 3
+    sacks.chunks_exact(3)
+        .map(|group| calculate_priority(&get_badge_type(&group[0], &group[1], &group[2])))
+        .sum()
+}
+
+pub struct Day3;
+
+impl Solver for Day3 {
+    const DAY: u8 = 3;
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(&self, sacks: &Self::Input) -> Answer {
+        part_1(sacks).into()
+    }
+
+    fn part_2(&self, sacks: &Self::Input) -> Answer {
+        part_2(sacks).into()
+    }
+}
+
+#[test]
+fn day_3_part_1() {
+    let input = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw";
+    const EXPECTED_VALUES: [(char, i32); 6] = [
+        ('p', 16),
+        ('L', 38),
+        ('P', 42),
+        ('v', 22),
+        ('t', 20),
+        ('s', 19)
+    ];
+    let mut total = 0;
+    for (index, line) in input.lines().enumerate() {
+        let sack_type = get_rucksack_type(line);
+        assert_eq!(EXPECTED_VALUES[index].0, sack_type);
+        let priority = calculate_priority(&sack_type);
+        assert_eq!(EXPECTED_VALUES[index].1, priority);
+        total += priority;
+    }
+    assert_eq!(157, total);
+    assert_eq!(157, part_1(&parse(input).unwrap()));
+}
+
+#[test]
+fn day_3_part_2() {
+    let input = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw";
+    const EXPECTED_TYPES: [char; 2] = ['r', 'Z'];
+    let mut lines = input.lines();
+    let mut total = 0;
+    for expected in EXPECTED_TYPES {
+        // Inputs are assumed to be divisible by 3 as the elves
+        // travel in groups of 3
+        let first = lines.next().unwrap();
+        let second = lines.next().unwrap();
+        let third = lines.next().unwrap();
+        let badge_type = get_badge_type(first, second, third);
+        assert_eq!(expected, badge_type);
+        total += calculate_priority(&badge_type);
+    }
+    assert_eq!(70, total);
+    assert_eq!(70, part_2(&parse(input).unwrap()));
+}
+
+#[test]
+fn day_3_parse_error() {
+    assert_eq!(Err(ParseError::unexpected(2, 3, "1", "an item type, a letter")), parse("abca\nab1b\naa"));
+    assert_eq!(Err(ParseError::unexpected(1, 1, "abc", "an even number of item types, half in each compartment")), parse("abc"));
+    assert_eq!(Err(ParseError::unexpected(2, 3, "cd", "a compartment sharing an item type with the first")), parse("abca\nabcd\naa"));
+    assert_eq!(Err(ParseError::missing(3, 1, "a rucksack, as elves travel in groups of three")), parse("abca\naa"));
+    assert_eq!(Err(ParseError::unexpected(3, 1, "bb", "a rucksack sharing a badge type with the rest of its group")), parse("abca\naa\nbb"));
+}
@@ -0,0 +1,132 @@
+use std::ops::Range;
+use crate::error::{ParseError, column_of, parse_number};
+use crate::solver::{Answer, Solver};
+
+/// Section assignments of a pair of elves
+pub type Assignments = (Range<i32>, Range<i32>);
+
+// Honestly I fully expected this to be a function that already existed for Ranges
+pub fn range_contains_range<T: PartialOrd>(this: &Range<T>, other: &Range<T>) -> bool {
+    this.start <= other.start && this.end >= other.end
+}
+
+/// Parses a section assignment such as `2-4`
+pub fn get_range(s: &str) -> Result<Range<i32>, ParseError> {
+    let (left, right) = s.split_once('-')
+        .ok_or_else(|| ParseError::missing(1, s.chars().count() + 1, "'-' between the first and last section"))?;
+    Ok(parse_number(s, left)?..parse_number(s, right)?)
+}
+
+pub fn does_pair_fully_contain(left: &str, right: &str) -> bool {
+    let left_range = get_range(left).expect("Invalid input given for get_range");
+    let right_range = get_range(right).expect("Invalid input given for get_range");
+    range_contains_range(&left_range, &right_range) || range_contains_range(&right_range, &left_range)
+}
+
+pub fn parse_assignment_pair(s: &&str) -> bool {
+    let (left, right) = s.split_once(',').unwrap();
+    does_pair_fully_contain(left, right)
+}
+
+// This one as well
+pub fn range_overlaps_range<T: PartialOrd>(this: &Range<T>, other: &Range<T>) -> bool {
+    range_contains_range(this, other)
+    || this.start <= other.end && this.start >= other.start
+    || this.end >= other.start && this.end <= other.end
+}
+
+pub fn does_pair_overlap(left: &str, right: &str) -> bool {
+    let left_range = get_range(left).expect("Invalid input given for get_range");
+    let right_range = get_range(right).expect("Invalid input given for get_range");
+    range_overlaps_range(&left_range, &right_range)
+}
+
+pub fn parse_assignment_pair_updated(s: &&str) -> bool {
+    let (left, right) = s.split_once(',').unwrap();
+    does_pair_overlap(left, right)
+}
+
+/// Each line's pair of section assignments
+pub fn parse(input: &str) -> Result<Vec<Assignments>, ParseError> {
+    input.lines()
+        .enumerate()
+        .map(|(index, line)| {
+            let (left, right) = line.split_once(',')
+                .ok_or_else(|| ParseError::missing(index + 1, line.chars().count() + 1, "',' between the two assignments"))?;
+            let left_range = get_range(left).map_err(|e| e.offset_lines(index))?;
+            let right_range = get_range(right).map_err(|e| e.offset_lines(index).offset_columns(column_of(line, right) - 1))?;
+            Ok((left_range, right_range))
+        })
+        .collect()
+}
+
+/// Number of pairs where one assignment fully contains the other
+pub fn part_1(pairs: &[Assignments]) -> usize {
+    pairs.iter()
+        .filter(|(left, right)| range_contains_range(left, right) || range_contains_range(right, left))
+        .count()
+}
+
+/// Number of pairs whose assignments overlap at all
+pub fn part_2(pairs: &[Assignments]) -> usize {
+    pairs.iter()
+        .filter(|(left, right)| range_overlaps_range(left, right))
+        .count()
+}
+
+pub struct Day4;
+
+impl Solver for Day4 {
+    const DAY: u8 = 4;
+    type Input = Vec<Assignments>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(&self, pairs: &Self::Input) -> Answer {
+        part_1(pairs).into()
+    }
+
+    fn part_2(&self, pairs: &Self::Input) -> Answer {
+        part_2(pairs).into()
+    }
+}
+
+#[test]
+fn day_4_part_1() {
+    let input = "2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8";
+    const EXPECTED_VALUE: usize = 2;
+    let total = input.lines()
+        .filter(parse_assignment_pair)
+        .count();
+    assert_eq!(EXPECTED_VALUE, total);
+    assert_eq!(EXPECTED_VALUE, part_1(&parse(input).unwrap()));
+}
+
+#[test]
+fn day_4_part_2() {
+    let input = "2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8";
+    const EXPECTED_VALUE: usize = 4;
+    let total = input.lines()
+        .filter(parse_assignment_pair_updated)
+        .count();
+    assert_eq!(EXPECTED_VALUE, total, "Calculated total was {}", total);
+    assert_eq!(EXPECTED_VALUE, part_2(&parse(input).unwrap()));
+}
+
+#[test]
+fn day_4_parse_error() {
+    assert_eq!(Err(ParseError::invalid_number(2, 7, "x")), parse("2-4,6-8\n2-3,4-x"));
+    assert_eq!(Err(ParseError::missing(1, 4, "',' between the two assignments")), parse("2-4"));
+}
@@ -0,0 +1,219 @@
+use std::str::FromStr;
+use crate::error::{ParseError, column_of, parse_number};
+use crate::scan;
+use crate::solver::{Answer, Solver};
+use crate::utils::paragraphs;
+
+pub enum CrateMoverVersion {
+    /// Moves crates one at a time
+    NineThousand,
+    /// Moves multiple crates at once, retaining their order
+    NineThousandOne
+}
+
+pub struct Instruction {
+    pub crates: usize,
+    /// 0-indexed stack to take crates from
+    pub start: usize,
+    /// 0-indexed stack to put crates onto
+    pub dest: usize
+}
+
+/// Parses `move <crates> from <start> to <dest>`, where stacks are numbered from 1
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields = scan::fields(s, "move {} from {} to {}")?;
+        let (crates, start, dest) = (parse_number::<usize>(s, fields[0])?, parse_number::<usize>(s, fields[1])?, parse_number::<usize>(s, fields[2])?);
+        if let Some(&stack) = fields[1..].iter().find(|&&field| field == "0") {
+            return Err(ParseError::unexpected(1, column_of(s, stack), stack, "a stack number starting from 1"));
+        }
+
+        Ok(Instruction { crates, start: start - 1, dest: dest - 1 })
+    }
+}
+
+/// Starting stacks of crates, each ordered from bottom to top, and the rearrangement procedure
+pub struct Cargo {
+    pub stacks: Vec<Vec<char>>,
+    pub instructions: Vec<Instruction>
+}
+
+impl Cargo {
+    /// Applies every instruction to a copy of the starting stacks
+    pub fn rearrange(&self, version: CrateMoverVersion) -> Vec<Vec<char>> {
+        let mut crates = self.stacks.clone();
+        for instruction in self.instructions.iter() {
+            match version {
+                CrateMoverVersion::NineThousand => {
+                    for _ in 0..instruction.crates {
+                        let to_move = crates[instruction.start].pop().expect("Attempt to move non-existent crate");
+                        crates[instruction.dest].push(to_move);
+                    }
+                }
+                CrateMoverVersion::NineThousandOne => {
+                    let mut to_move = Vec::new();
+                    for _ in 0..instruction.crates {
+                        to_move.push(crates[instruction.start].pop().expect("Attempt to move non-existent crate"));
+                    }
+                    for to_move in to_move.iter().rev() {
+                        crates[instruction.dest].push(*to_move);
+                    }
+                }
+            }
+        }
+
+        crates
+    }
+}
+
+/// Message spelled out by the top crate of each stack, with a space for empty stacks
+pub fn top_crates(stacks: &[Vec<char>]) -> String {
+    let mut message = String::new();
+    for stack in stacks {
+        match stack.last() {
+            Some(char) => message.push(*char),
+            None => message.push(' ')
+        }
+    }
+    
+    message
+}
+
+fn parse_crate_string(s: &&str) -> Vec<(usize, char)> {
+    let mut crates = Vec::new();
+    for (index, char) in s.chars().enumerate() {
+        if char.is_alphabetic() {
+            crates.push((index / 4, char));
+        }
+    }
+
+    crates
+}
+
+/// The drawing of the starting stacks, followed by a blank line and the rearrangement procedure
+pub fn parse(input: &str) -> Result<Cargo, ParseError> {
+    let mut paragraphs = paragraphs(input);
+    let (drawing_start, drawing) = paragraphs.next()
+        .ok_or_else(|| ParseError::missing(1, 1, "a drawing of the starting stacks"))?;
+    let initial_state = drawing.lines()
+        .enumerate()
+        .map(|(index, s)| (drawing_start + index, s))
+        .collect::<Vec<_>>();
+    let instructions = match paragraphs.next() {
+        Some((start, procedure)) => procedure.lines().enumerate().map(|(index, s)| (start + index, s)).collect(),
+        None => Vec::new()
+    };
+    if let Some((start, rest)) = paragraphs.next() {
+        return Err(ParseError::unexpected(start + 1, 1, rest.lines().next().unwrap_or(rest), "the end of the procedure"));
+    }
+    // A paragraph always has a line
+    let num_stacks = (initial_state[0].1.len() + 1) / 4;
+    let initial_state = initial_state.iter().rev()
+            .filter(|(_, s)| s.contains('['))
+            .map(|(index, s)| (index, parse_crate_string(s)));
+    let mut stacks: Vec<Vec<char>> = Vec::new();
+    for _ in 0..num_stacks {
+        stacks.push(Vec::new());
+    }
+    for (index, crate_info) in initial_state {
+        for (pos, char) in crate_info {
+            match stacks.get_mut(pos) {
+                Some(stack) => stack.push(char),
+                None => return Err(ParseError::unexpected(index + 1, pos * 4 + 2, &char.to_string(), &format!("at most {} stacks", num_stacks)))
+            }
+        }
+    }
+
+    let instructions = instructions.iter()
+        .map(|(index, s)| {
+            let instruction = s.parse::<Instruction>().map_err(|e| e.offset_lines(*index))?;
+            match [instruction.start, instruction.dest].into_iter().find(|&stack| stack >= num_stacks) {
+                Some(stack) => Err(ParseError::unexpected(index + 1, 1, &(stack + 1).to_string(), &format!("a stack between 1 and {}", num_stacks))),
+                None => Ok(instruction)
+            }
+        })
+        .collect::<Result<Vec<Instruction>, ParseError>>()?;
+
+    Ok(Cargo { stacks, instructions })
+}
+
+pub fn part_1(cargo: &Cargo) -> String {
+    top_crates(&cargo.rearrange(CrateMoverVersion::NineThousand))
+}
+
+pub fn part_2(cargo: &Cargo) -> String {
+    top_crates(&cargo.rearrange(CrateMoverVersion::NineThousandOne))
+}
+
+pub struct Day5;
+
+impl Solver for Day5 {
+    const DAY: u8 = 5;
+    type Input = Cargo;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(&self, cargo: &Self::Input) -> Answer {
+        part_1(cargo).into()
+    }
+
+    fn part_2(&self, cargo: &Self::Input) -> Answer {
+        part_2(cargo).into()
+    }
+}
+
+#[test]
+fn day_5_part_1() {
+    const TEST_INPUT: &str =
+"    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2";
+
+    const EXPECTED_CRATES: &str = "CMZ";
+    
+    assert_eq!(EXPECTED_CRATES, part_1(&parse(TEST_INPUT).unwrap()));
+}
+
+#[test]
+fn day_5_part_2() {
+    const TEST_INPUT: &str =
+"    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2";
+
+    const EXPECTED_CRATES: &str = "MCD";
+    
+    assert_eq!(EXPECTED_CRATES, part_2(&parse(TEST_INPUT).unwrap()));
+}
+
+#[test]
+fn day_5_parse_error() {
+    const TEST_INPUT: &str =
+"[N] [C]
+ 1   2 
+
+move 1 from 2 to 1
+move 3 form 1 to 2
+move 1 from 1 to 3";
+
+    assert_eq!(Err(ParseError::unexpected(5, 8, "form", "'from'")), parse(TEST_INPUT).map(|_| ()));
+    assert_eq!(Err(ParseError::invalid_number(1, 6, "x")), "move x from 1 to 2".parse::<Instruction>().map(|_| ()));
+    assert_eq!(Err(ParseError::unexpected(6, 1, "3", "a stack between 1 and 2")), parse(&TEST_INPUT.replace("form", "from")).map(|_| ()));
+    assert_eq!(Err(ParseError::unexpected(8, 1, "move 1 from 1 to 2", "the end of the procedure")), parse(&format!("{}\n\nmove 1 from 1 to 2", TEST_INPUT)).map(|_| ()));
+}
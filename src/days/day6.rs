@@ -0,0 +1,114 @@
+use crate::error::ParseError;
+use crate::solver::{Answer, Solver};
+
+/// Number of different characters in a row making up a start-of-packet marker
+pub const PACKET_MARKER_LEN: usize = 4;
+/// Number of different characters in a row making up a start-of-message marker. Being longer than a
+/// start-of-packet marker, finding one means there is a start-of-packet marker as well.
+pub const MESSAGE_MARKER_LEN: usize = 14;
+
+/// Number of characters processed before the last `offset` characters are all different,
+/// or `None` if that never happens
+pub fn get_data_start(msg: &str, offset: usize) -> Option<usize> {
+    let packets = msg.chars().collect::<Vec<char>>();
+    for index in offset..=packets.len() {
+        let chars = &packets[index - offset..index];
+        let mut should_return = true;
+        for char in chars.iter() {
+            if chars.iter().filter(|&c| c == char).count() > 1 {
+                should_return = false;
+                break;
+            }
+        }
+        if should_return { return Some(index) }
+    }
+    None
+}
+
+/// The datastream is a single line of characters, containing a start-of-message marker
+pub fn parse(input: &str) -> Result<String, ParseError> {
+    let mut lines = input.trim_end().lines();
+    let msg = lines.next().ok_or_else(|| ParseError::missing(1, 1, "a datastream"))?;
+    if let Some(line) = lines.next() {
+        return Err(ParseError::unexpected(2, 1, line, "the datastream to be a single line"));
+    }
+    if get_data_start(msg, MESSAGE_MARKER_LEN).is_none() {
+        let expected = format!("a start-of-message marker, {} different characters in a row", MESSAGE_MARKER_LEN);
+        return Err(ParseError::missing(1, msg.chars().count() + 1, &expected));
+    }
+
+    Ok(msg.to_string())
+}
+
+/// Position of the first start-of-packet marker
+///
+/// # Panics
+/// Panics if there is no marker, which [`parse`] rules out
+pub fn part_1(msg: &str) -> usize {
+    get_data_start(msg, PACKET_MARKER_LEN).expect("datastream should have a start-of-packet marker")
+}
+
+/// Position of the first start-of-message marker
+///
+/// # Panics
+/// Panics if there is no marker, which [`parse`] rules out
+pub fn part_2(msg: &str) -> usize {
+    get_data_start(msg, MESSAGE_MARKER_LEN).expect("datastream should have a start-of-message marker")
+}
+
+pub struct Day6;
+
+impl Solver for Day6 {
+    const DAY: u8 = 6;
+    type Input = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(&self, msg: &Self::Input) -> Answer {
+        part_1(msg).into()
+    }
+
+    fn part_2(&self, msg: &Self::Input) -> Answer {
+        part_2(msg).into()
+    }
+}
+
+#[test]
+fn day_6_part_1() {
+    let test_cases = [
+        ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5),
+        ("nppdvjthqldpwncqszvftbrmjlhg", 6),
+        ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10),
+        ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11)
+    ];
+    for (input, expected) in test_cases {
+        assert_eq!(Some(expected), get_data_start(input, 4));
+    }
+}
+
+#[test]
+fn day_6_part_2() {
+    let test_cases = [
+        ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 19),
+        ("bvwbjplbgvbhsrlpgdmjqwftvncz", 23),
+        ("nppdvjthqldpwncqszvftbrmjlhg", 23),
+        ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 29),
+        ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 26)
+    ];
+    for (input, expected) in test_cases {
+        assert_eq!(Some(expected), get_data_start(input, 14));
+    }
+}
+
+#[test]
+fn day_6_parse_error() {
+    let expected = "a start-of-message marker, 14 different characters in a row";
+    assert_eq!(Err(ParseError::missing(1, 4, expected)), parse("abc"));
+    assert_eq!(Err(ParseError::missing(1, 21, expected)), parse("abcdefghijabcdefghij"));
+    assert_eq!(Err(ParseError::unexpected(2, 1, "xyz", "the datastream to be a single line")), parse("abcdefghijklmn\nxyz"));
+    // A marker right at the end still counts
+    assert_eq!(Ok(14), parse("abcdefghijklmn").map(|msg| part_2(&msg)));
+    assert_eq!(None, get_data_start("aaa", 4));
+}
@@ -0,0 +1,256 @@
+use std::collections::HashMap;
+use std::cell::RefCell;
+use std::ops::{DerefMut, Deref};
+use crate::error::{ParseError, parse_number};
+use crate::solver::{Answer, Solver};
+
+pub const FILE_SYSTEM_CAPACITY: usize = 70000000;
+pub const UPDATE_SIZE: usize = 30000000;
+
+/// Tracks the total size of every directory, including the sizes of nested directories
+pub struct FileSystem {
+    files: RefCell<HashMap<String, RefCell<usize>>>,
+    current_dir: RefCell<Vec<String>>,
+}
+
+impl FileSystem {
+    pub fn new() -> Self {
+        FileSystem {
+            files: RefCell::new(HashMap::new()),
+            current_dir: RefCell::new(Vec::new())
+        }
+    }
+
+    /// Replays the terminal output of `cd` and `ls` commands
+    pub fn from_outputs(outputs: &str) -> Result<Self, ParseError> {
+        let filesystem = FileSystem::new();
+        filesystem.add_data("dir ")?;
+        for (index, output) in outputs.lines().enumerate() {
+            if let Some(command) = output.strip_prefix("$ ") {
+                match command.split_once(' ') {
+                    Some(("cd", dir)) => filesystem.change_directory(dir).map_err(|e| e.offset_lines(index).offset_columns(5))?,
+                    None if command == "ls" => {}
+                    _ => return Err(ParseError::unexpected(index + 1, 3, command, "either 'cd <dir>' or 'ls'"))
+                }
+            } else {
+                filesystem.add_data(output).map_err(|e| e.offset_lines(index))?;
+            }
+        }
+        Ok(filesystem)
+    }
+
+    /// Adds a line of `ls` output, i.e. `dir name` or `size name`, to the current directory
+    pub fn add_data(&self, s: &str) -> Result<(), ParseError> {
+        let (left, name) = s.split_once(' ')
+            .ok_or_else(|| ParseError::missing(1, s.chars().count() + 1, "a space between the size and the name"))?;
+        if left == "dir" {
+            if self.path().ends_with('/') {
+                self.files.borrow_mut().insert(self.path() + name, RefCell::new(0));
+            } else {
+                self.files.borrow_mut().insert(self.path() + "/" + name, RefCell::new(0));
+            }
+        } else {
+            let file_size = parse_number::<usize>(s, left)?;
+            for path in self.current_dir.borrow().iter() {
+                *self.files.borrow().get(path).expect("directory should exist").borrow_mut().deref_mut() += file_size;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The current directory
+    pub fn path(&self) -> String {
+        self.current_dir.borrow().last().cloned().unwrap_or_else(|| "/".to_string())
+    }
+
+    /// Changes into `dir`, which has to be `/`, `..` or a directory listed in the current one.
+    /// Errors point at the start of `dir`.
+    pub fn change_directory(&self, dir: &str) -> Result<(), ParseError> {
+        match dir {
+            "/" => {
+                self.current_dir.borrow_mut().clear();
+                self.current_dir.borrow_mut().push(String::from("/"));
+            }
+            ".." => { self.current_dir.borrow_mut().pop(); }
+            _ => {
+                let mut path = self.current_dir.borrow().last()
+                    .ok_or_else(|| ParseError::unexpected(1, 1, dir, "'/' before any other directory"))?
+                    .to_owned();
+                if !path.ends_with('/') {
+                    path.push('/');
+                }
+                let new_path = path + dir;
+                if !self.files.borrow().contains_key(&new_path) {
+                    return Err(ParseError::unexpected(1, 1, dir, "a directory listed by 'ls'"));
+                }
+                self.current_dir.borrow_mut().push(new_path)
+            }
+        }
+
+        Ok(())
+    }
+
+    /// # Panics
+    /// Panics if `path` is not a known directory
+    pub fn get_size(&self, path: &str) -> usize {
+        *self.files.borrow().get(path).expect("should be valid path").borrow().deref()
+    }
+
+    /// Sizes of every directory in no particular order
+    pub fn directory_sizes(&self) -> Vec<usize> {
+        self.files.borrow()
+            .values()
+            .map(|v| v.borrow().to_owned())
+            .collect()
+    }
+}
+
+impl Default for FileSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub fn parse(input: &str) -> Result<FileSystem, ParseError> {
+    FileSystem::from_outputs(input)
+}
+
+/// Total size of all directories with a size of at most 100000
+pub fn part_1(filesystem: &FileSystem) -> usize {
+    filesystem.directory_sizes()
+        .into_iter()
+        .filter(|&x| x <= 100000)
+        .sum()
+}
+
+/// Size of the smallest directory that frees up enough space for the update when deleted
+pub fn part_2(filesystem: &FileSystem) -> usize {
+    let used = filesystem.get_size("/");
+    // Saturating, as there may already be enough room, or more used than there is capacity for;
+    // deleting the root frees up enough either way
+    let unused = FILE_SYSTEM_CAPACITY.saturating_sub(used);
+    let required_space = UPDATE_SIZE.saturating_sub(unused);
+
+    filesystem.directory_sizes()
+        .into_iter()
+        .filter(|&size| size >= required_space)
+        .min()
+        .expect("deleting the root should free up enough space")
+}
+
+pub struct Day7;
+
+impl Solver for Day7 {
+    const DAY: u8 = 7;
+    type Input = FileSystem;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(&self, filesystem: &Self::Input) -> Answer {
+        part_1(filesystem).into()
+    }
+
+    fn part_2(&self, filesystem: &Self::Input) -> Answer {
+        part_2(filesystem).into()
+    }
+}
+
+/// Example terminal output from the puzzle description
+pub const EXAMPLE: &str =
+"$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k";
+
+#[test]
+fn day_7_part_1() {
+    let test_outputs = EXAMPLE;
+
+    let expected_values = [
+        ("/", 48381165),
+        ("/d", 24933642),
+        ("/a", 94853),
+        ("/a/e", 584)
+    ];
+
+    let filesystem = FileSystem::from_outputs(test_outputs).unwrap();
+
+    let mut total = 0;
+    for (path, size) in expected_values {
+        println!("Directory {} is expected to have size {}", path, size);
+        let calc_size = filesystem.get_size(path);
+        assert_eq!(size, calc_size);
+        if calc_size <= 100000 { total += calc_size }
+    }
+    assert_eq!(95437, total);
+    assert_eq!(95437, part_1(&filesystem));
+}
+
+#[test]
+fn day_7_part_2() {
+    let test_outputs = EXAMPLE;
+    let expected_deletion = String::from("/d");
+    let expected_size = 24933642;
+    let filesystem = FileSystem::from_outputs(test_outputs).unwrap();
+    let unused = FILE_SYSTEM_CAPACITY - filesystem.get_size("/");
+    let required_space = UPDATE_SIZE - unused;
+    let files =  filesystem.files.borrow();
+    let to_delete = files.keys()
+        .filter(|path| {
+            filesystem.get_size(path) > required_space
+        }).min_by(|a, b| {
+            filesystem.get_size(a).cmp(&filesystem.get_size(b))
+        }).expect("Did not find a folder that fulfills the requirements");
+    assert_eq!(&expected_deletion, to_delete);
+    assert_eq!(expected_size, filesystem.get_size(to_delete));
+    assert_eq!(expected_size, part_2(&filesystem));
+
+    assert_eq!(70000001, part_2(&parse("$ cd /\n$ ls\n70000001 f").unwrap()));
+    assert_eq!(0, part_2(&parse("").unwrap()));
+}
+
+#[test]
+fn day_7_validate_size() {
+    // The real input is only checked when it is available
+    let real_input = crate::inputs::read_day(7).ok();
+    for outputs in [Some(EXAMPLE.to_string()), real_input].into_iter().flatten() {
+        let mut expected_sum = 0;
+        for line in outputs.lines() {
+            if let Ok(n) = line.split_whitespace().next().unwrap().parse::<usize>() {
+                expected_sum += n;
+            }
+        }
+        let filesystem = FileSystem::from_outputs(&outputs).unwrap();
+        assert_eq!(expected_sum, filesystem.get_size("/"));
+    }
+}
+
+#[test]
+fn day_7_parse_error() {
+    assert_eq!(Err(ParseError::unexpected(2, 6, "x", "a directory listed by 'ls'")), parse("$ cd /\n$ cd x\n$ ls\n10 f").map(|_| ()));
+    assert_eq!(Err(ParseError::unexpected(1, 6, "a", "'/' before any other directory")), parse("$ cd a\n$ ls").map(|_| ()));
+    assert_eq!(Err(ParseError::unexpected(2, 3, "dir", "either 'cd <dir>' or 'ls'")), parse("$ cd /\n$ dir").map(|_| ()));
+    assert_eq!(Err(ParseError::invalid_number(3, 1, "ten")), parse("$ cd /\n$ ls\nten f").map(|_| ()));
+}
@@ -0,0 +1,175 @@
+use std::collections::HashSet;
+use crate::error::ParseError;
+use crate::grid::Grid;
+use crate::point::Point;
+use crate::solver::{Answer, Solver};
+
+#[derive(Clone, Copy)]
+pub enum Towards {
+    X(i32),
+    Y(i32)
+}
+
+/// Line of sight into the grid, with the index of the row or column being looked along
+#[allow(clippy::enum_variant_names)]
+pub enum Direction {
+    FromTop(usize),
+    FromBottom(usize),
+    FromLeft(usize),
+    FromRight(usize)
+}
+
+/// Positions along the line of sight, starting from the edge of the grid
+fn line_of_sight(trees: &Grid<u32>, direction: &Direction) -> Vec<Point<i32>> {
+    let (width, height) = (trees.width() as i32, trees.height() as i32);
+    match *direction {
+        Direction::FromTop(index) => (0..height).map(|y| Point::new(index as i32, y)).collect(),
+        Direction::FromBottom(index) => (0..height).rev().map(|y| Point::new(index as i32, y)).collect(),
+        Direction::FromLeft(index) => (0..width).map(|x| Point::new(x, index as i32)).collect(),
+        Direction::FromRight(index) => (0..width).rev().map(|x| Point::new(x, index as i32)).collect()
+    }
+}
+
+/// Positions of the trees that are visible when looking from the given direction
+pub fn get_visible_from(trees: &Grid<u32>, direction: Direction) -> Vec<Point<i32>> {
+    let line = line_of_sight(trees, &direction);
+    let maximum = line.iter().map(|&point| trees[point]).max().unwrap();
+    let mut visible_coords = Vec::new();
+    let mut tallest = 0;
+    for (index, &point) in line.iter().enumerate() {
+        if index == 0 || trees[point] > tallest {
+            tallest = trees[point];
+            visible_coords.push(point);
+            if tallest == maximum { break; }
+        }
+    }
+
+    visible_coords
+}
+
+pub fn get_all_visible(trees: &Grid<u32>) -> HashSet<Point<i32>> {
+    let mut visible_coords = HashSet::new();
+    for x in 0..trees.width() {
+        visible_coords.extend(get_visible_from(trees, Direction::FromTop(x)));
+        visible_coords.extend(get_visible_from(trees, Direction::FromBottom(x)));
+    }
+    for y in 0..trees.height() {
+        visible_coords.extend(get_visible_from(trees, Direction::FromLeft(y)));
+        visible_coords.extend(get_visible_from(trees, Direction::FromRight(y)));
+    }
+
+    visible_coords
+}
+
+/// Number of trees that can be seen from the tree at `point` when looking along `t`
+pub fn get_viewing_distance(trees: &Grid<u32>, point: Point<i32>, t: Towards) -> i32 {
+    let step = match t {
+        Towards::X(step) => (step, 0),
+        Towards::Y(step) => (0, step)
+    };
+    let height = trees[point];
+    let mut distance = 0;
+    let mut current = point.add_tuple(step);
+    while let Some(&other) = trees.get(current) {
+        distance += 1;
+        if other == height { break; }
+        current = current.add_tuple(step);
+    }
+
+    distance
+}
+
+pub fn get_scenic_score(trees: &Grid<u32>, point: Point<i32>) -> i32 {
+    [Towards::X(-1), Towards::X(1), Towards::Y(-1), Towards::Y(1)]
+        .iter()
+        .map(|&t| get_viewing_distance(trees, point, t))
+        .product()
+}
+
+/// Grid of single digit tree heights
+pub fn parse(input: &str) -> Result<Grid<u32>, ParseError> {
+    Grid::parse_chars(input, "a tree height from 0 to 9", |c| c.to_digit(10))
+}
+
+/// Number of trees visible from outside the grid
+pub fn part_1(trees: &Grid<u32>) -> usize {
+    get_all_visible(trees).len()
+}
+
+/// Highest scenic score of any tree
+pub fn part_2(trees: &Grid<u32>) -> i32 {
+    get_all_visible(trees).iter()
+        .map(|&point| get_scenic_score(trees, point))
+        .max()
+        .unwrap()
+}
+
+pub struct Day8;
+
+impl Solver for Day8 {
+    const DAY: u8 = 8;
+    type Input = Grid<u32>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(&self, trees: &Self::Input) -> Answer {
+        part_1(trees).into()
+    }
+
+    fn part_2(&self, trees: &Self::Input) -> Answer {
+        part_2(trees).into()
+    }
+}
+
+#[test]
+fn day_8_part_1() {
+    let test_input =
+"30373
+25512
+65332
+33549
+35390";
+    let v = parse(test_input).unwrap();
+
+    assert_eq!(21, get_all_visible(&v).len());
+
+    // Number of trees visible from left and right
+    let expect_left_expect_right = [
+        (2, 2),
+        (2, 2),
+        (1, 4),
+        (3, 1),
+        (3, 2)
+    ];
+    for (row_num, (expected_left, expected_right)) in expect_left_expect_right.iter().enumerate() {
+        assert_eq!(expected_left, &get_visible_from(&v, Direction::FromLeft(row_num)).len());
+        assert_eq!(expected_right, &get_visible_from(&v, Direction::FromRight(row_num)).len());
+    }
+}
+
+#[test]
+fn day_8_part_2() {
+    let test_input =
+"30373
+25512
+65332
+33549
+35390";
+    let v = parse(test_input).unwrap();
+    let expected_max = 8;
+    let expected_height = 5;
+    let mut calc_max = 0;
+    let mut found_height = 0;
+    for point in get_all_visible(&v) {
+        let score = get_scenic_score(&v, point);
+        if score > calc_max {
+            calc_max = score;
+            found_height = v[point];
+        }
+    }
+
+    assert_eq!(expected_height, found_height);
+    assert_eq!(expected_max, calc_max);
+}
@@ -0,0 +1,203 @@
+use std::str::FromStr;
+use crate::error::{ParseError, parse_lines, parse_number};
+use crate::grid::SparseGrid;
+use crate::point::Point;
+use crate::solver::{Answer, Solver};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Direction {
+    Left(usize),
+    Right(usize),
+    Up(usize),
+    Down(usize)
+}
+
+/// Parses a motion such as `R 4`
+impl FromStr for Direction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (left, right) = s.split_once(' ')
+            .ok_or_else(|| ParseError::missing(1, s.chars().count() + 1, "a space between the direction and the number of steps"))?;
+        let n = parse_number::<usize>(s, right)?;
+        match left {
+            "L" => Ok(Direction::Left(n)),
+            "R" => Ok(Direction::Right(n)),
+            "U" => Ok(Direction::Up(n)),
+            "D" => Ok(Direction::Down(n)),
+            _ => Err(ParseError::unexpected(1, 1, left, "one of L, R, U or D"))
+        }
+    }
+}
+
+pub struct Rope {
+    pub head: Point<i64>,
+    pub knots: Vec<Point<i64>>,
+    /// Every position the last knot has visited
+    pub traversed: SparseGrid<bool>
+}
+
+impl Rope {
+    /// # Panics
+    /// Panics if `length` is less than 2
+    pub fn new(length: usize) -> Self {
+        assert!(length > 1, "Rope must have at least 2 knots");
+        let mut rope = Rope {
+            head: Point::zero(),
+            knots: vec![Point::zero(); length],
+            traversed: SparseGrid::new()
+        };
+        rope.traversed.insert(Point::zero(), true);
+        rope
+    }
+
+    pub fn move_head(&mut self, direction: Direction) {
+        let (step, n) = match direction {
+            Direction::Left(n) => ((-1, 0), n),
+            Direction::Right(n) => ((1, 0), n),
+            Direction::Up(n) => ((0, 1), n),
+            Direction::Down(n) => ((0, -1), n)
+        };
+        for _ in 0..n {
+            self.move_to(self.head.add_tuple(step));
+        }
+    }
+
+    pub fn move_to(&mut self, position: Point<i64>) {
+        self.head = position;
+        self.knots[0] = position;
+
+        self.move_next(0);
+    }
+
+    fn move_next(&mut self, current_num: usize) {
+        let Point { x, y } = self.knots[current_num];
+        let knot = &mut self.knots[current_num + 1];
+        if (x - knot.x).abs() == 2 && (y - knot.y).abs() == 1
+        || (x - knot.x).abs() == 1 && (y - knot.y).abs() == 2
+        || (x - knot.x).abs() > 1 || (y - knot.y).abs() > 1 {
+            knot.x += (x - knot.x).signum();
+            knot.y += (y - knot.y).signum();
+        }
+        if current_num == self.knots.len() - 2 {
+            self.traversed.insert(self.knots[current_num + 1], true);
+        } else {
+            self.move_next(current_num + 1);
+        }
+    }
+
+    /// Draws the knots as `H` for the head and their index for the rest, with `s` as the starting position.
+    /// Only covers the region the knots and start are in, with up being towards the top.
+    pub fn render(&self) -> String {
+        let mut grid = SparseGrid::new();
+        grid.insert(Point::zero(), 's');
+        // Flipped vertically as rows are drawn from the top down
+        for (index, knot) in self.knots.iter().enumerate().rev() {
+            let c = if index == 0 { 'H' } else { std::char::from_digit(index as u32 % 10, 10).unwrap() };
+            grid.insert(Point::new(knot.x, -knot.y), c);
+        }
+
+        grid.render('.')
+    }
+}
+
+/// Number of positions visited by the last knot of a rope with `length` knots
+pub fn count_traversed(directions: &[Direction], length: usize) -> usize {
+    let mut rope = Rope::new(length);
+    for &direction in directions {
+        rope.move_head(direction);
+    }
+    rope.traversed.len()
+}
+
+pub fn parse(input: &str) -> Result<Vec<Direction>, ParseError> {
+    parse_lines(input)
+}
+
+pub fn part_1(directions: &[Direction]) -> usize {
+    count_traversed(directions, 2)
+}
+
+pub fn part_2(directions: &[Direction]) -> usize {
+    count_traversed(directions, 10)
+}
+
+pub struct Day9;
+
+impl Solver for Day9 {
+    const DAY: u8 = 9;
+    type Input = Vec<Direction>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_1(&self, directions: &Self::Input) -> Answer {
+        part_1(directions).into()
+    }
+
+    fn part_2(&self, directions: &Self::Input) -> Answer {
+        part_2(directions).into()
+    }
+}
+
+#[test]
+fn day_9_part_1() {
+    let test_input = 
+"R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2";
+    let expected_traversed = 13;
+    let mut rope = Rope::new(2);
+    for line in test_input.lines() {
+        rope.move_head(line.parse().unwrap());
+    }
+    assert_eq!(expected_traversed, rope.traversed.len());
+}
+
+#[test]
+fn day_9_part_2() {
+    let test_input_1 = 
+"R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2";
+    let test_input_2 = 
+"R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20";
+    let expected_traversed_1 = 1;
+    let mut rope = Rope::new(10);
+    for line in test_input_1.lines() {
+        rope.move_head(line.parse().unwrap());
+        println!("{}", rope.render());
+    }
+    assert_eq!(expected_traversed_1, rope.traversed.len());
+
+    let expected_traversed_2 = 36;
+    let mut rope = Rope::new(10);
+    for line in test_input_2.lines() {
+        rope.move_head(line.parse().unwrap());
+    }
+    assert_eq!(expected_traversed_2, rope.traversed.len());
+}
+
+#[test]
+fn day_9_parse_error() {
+    assert_eq!(Err(ParseError::unexpected(2, 1, "X", "one of L, R, U or D")), parse("R 4\nX 4"));
+    assert_eq!(Err(ParseError::invalid_number(1, 3, "-4")), parse("R -4"));
+}
@@ -0,0 +1,116 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// Error for malformed puzzle input, pointing at where the problem was found.
+///
+/// `line` and `column` are 1-based and relative to the text that was being parsed;
+/// parsers that are handed a single line or a group of lines report positions within that text,
+/// and their callers shift them with [`ParseError::offset_lines`] and [`ParseError::offset_columns`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// `text` is not what was expected at this position
+    Unexpected { line: usize, column: usize, text: String, expected: String },
+    /// `text` should have been a number that fits the type being parsed into
+    InvalidNumber { line: usize, column: usize, text: String },
+    /// The text ended before something that was expected was found
+    Missing { line: usize, column: usize, expected: String }
+}
+
+impl ParseError {
+    pub fn unexpected(line: usize, column: usize, text: &str, expected: &str) -> Self {
+        ParseError::Unexpected { line, column, text: text.to_string(), expected: expected.to_string() }
+    }
+
+    pub fn invalid_number(line: usize, column: usize, text: &str) -> Self {
+        ParseError::InvalidNumber { line, column, text: text.to_string() }
+    }
+
+    pub fn missing(line: usize, column: usize, expected: &str) -> Self {
+        ParseError::Missing { line, column, expected: expected.to_string() }
+    }
+
+    pub fn line(&self) -> usize {
+        match self {
+            ParseError::Unexpected { line, .. } | ParseError::InvalidNumber { line, .. } | ParseError::Missing { line, .. } => *line
+        }
+    }
+
+    pub fn column(&self) -> usize {
+        match self {
+            ParseError::Unexpected { column, .. } | ParseError::InvalidNumber { column, .. } | ParseError::Missing { column, .. } => *column
+        }
+    }
+
+    /// Offending text, if there was any
+    pub fn text(&self) -> Option<&str> {
+        match self {
+            ParseError::Unexpected { text, .. } | ParseError::InvalidNumber { text, .. } => Some(text),
+            ParseError::Missing { .. } => None
+        }
+    }
+
+    /// Moves the error down by `n` lines, for when the text that was parsed did not start on the first line
+    pub fn offset_lines(mut self, n: usize) -> Self {
+        match &mut self {
+            ParseError::Unexpected { line, .. } | ParseError::InvalidNumber { line, .. } | ParseError::Missing { line, .. } => *line += n
+        }
+        self
+    }
+
+    /// Moves the error right by `n` columns, for when the text that was parsed did not start at the beginning of its line
+    pub fn offset_columns(mut self, n: usize) -> Self {
+        match &mut self {
+            ParseError::Unexpected { column, .. } | ParseError::InvalidNumber { column, .. } | ParseError::Missing { column, .. } => *column += n
+        }
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: ", self.line(), self.column())?;
+        match self {
+            ParseError::Unexpected { text, expected, .. } => write!(f, "expected {}, found '{}'", expected, text),
+            ParseError::InvalidNumber { text, .. } => write!(f, "'{}' is not a valid number", text),
+            ParseError::Missing { expected, .. } => write!(f, "expected {}", expected)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// 1-based column that `part` starts at within `line`.
+///
+/// # Panics
+/// Panics if `part` is not a slice of `line`, i.e. it was not obtained by splitting/trimming/slicing `line`
+pub fn column_of(line: &str, part: &str) -> usize {
+    let offset = (part.as_ptr() as usize).checked_sub(line.as_ptr() as usize)
+        .filter(|&offset| offset + part.len() <= line.len())
+        .expect("part should be a slice of line");
+
+    line[..offset].chars().count() + 1
+}
+
+/// Parses `text`, a slice of `line`, into a number, reporting the column of `text` if it is invalid
+pub fn parse_number<T: FromStr>(line: &str, text: &str) -> Result<T, ParseError> {
+    text.parse::<T>().map_err(|_| ParseError::invalid_number(1, column_of(line, text), text))
+}
+
+/// Parses every line of `input`, setting the line number of any error encountered
+pub fn parse_lines<T>(input: &str) -> Result<Vec<T>, ParseError> where T: FromStr<Err = ParseError> {
+    input.lines()
+        .enumerate()
+        .map(|(index, line)| line.parse::<T>().map_err(|e| e.offset_lines(index)))
+        .collect()
+}
+
+#[test]
+fn parse_error_position_test() {
+    let line = "move 1 from x to 3";
+    let word = line.split_whitespace().nth(3).unwrap();
+    assert_eq!(13, column_of(line, word));
+
+    let error = parse_number::<usize>(line, word).unwrap_err();
+    assert_eq!(ParseError::invalid_number(1, 13, "x"), error);
+    assert_eq!("line 4, column 13: 'x' is not a valid number", error.offset_lines(3).to_string());
+}
@@ -0,0 +1,212 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// Directed graph, where the nodes are identified by small copyable values such as indexes or positions
+pub trait Graph {
+    type Node: Copy + Eq + Hash;
+
+    /// Nodes that can be reached from `node` in a single step
+    fn neighbors(&self, node: Self::Node) -> Vec<Self::Node>;
+
+    /// Cost of stepping from `from` to its neighbor `to`
+    fn weight(&self, _from: Self::Node, _to: Self::Node) -> u64 {
+        1
+    }
+}
+
+/// Route through a graph, including both ends
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N> {
+    /// Nodes from the start to the end
+    pub nodes: Vec<N>,
+    /// Sum of the weights of every step taken
+    pub cost: u64
+}
+
+impl<N> Path<N> {
+    /// Number of steps taken, which is one less than the number of nodes
+    pub fn len(&self) -> usize {
+        self.nodes.len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn start(&self) -> &N {
+        &self.nodes[0]
+    }
+
+    pub fn end(&self) -> &N {
+        &self.nodes[self.nodes.len() - 1]
+    }
+}
+
+/// Follows the `parents` links back from `end`, returning the nodes in order from the start to `end`
+pub fn reconstruct_path<N: Copy + Eq + Hash>(parents: &HashMap<N, N>, end: N) -> Vec<N> {
+    let mut path = vec![end];
+    let mut current = end;
+    while let Some(&parent) = parents.get(&current) {
+        path.push(parent);
+        current = parent;
+    }
+    path.reverse();
+
+    path
+}
+
+/// Entry in the open set, ordered so that `BinaryHeap` pops the lowest estimate first
+struct Candidate<N> {
+    estimate: u64,
+    cost: u64,
+    node: N
+}
+
+impl<N> PartialEq for Candidate<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.estimate == other.estimate && self.cost == other.cost
+    }
+}
+
+impl<N> Eq for Candidate<N> {}
+
+impl<N> PartialOrd for Candidate<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N> Ord for Candidate<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed for a min-heap, preferring the candidate that has got further on a tie
+        other.estimate.cmp(&self.estimate).then(self.cost.cmp(&other.cost))
+    }
+}
+
+/// Cheapest path from `start` to the first node satisfying `is_target`, or `None` if there is none.
+/// `heuristic` must never overestimate the remaining cost for the path to be the cheapest. It doesn't have to be
+/// consistent, as nodes that have already been searched from are searched again if a cheaper way to them turns up.
+pub fn a_star<G, T, H>(graph: &G, start: G::Node, is_target: T, heuristic: H) -> Option<Path<G::Node>>
+    where
+        G: Graph,
+        T: Fn(G::Node) -> bool,
+        H: Fn(G::Node) -> u64 {
+    let mut costs = HashMap::new();
+    let mut parents = HashMap::new();
+    let mut open = BinaryHeap::new();
+    costs.insert(start, 0);
+    open.push(Candidate { estimate: heuristic(start), cost: 0, node: start });
+
+    while let Some(Candidate { cost, node, .. }) = open.pop() {
+        if is_target(node) {
+            return Some(Path { nodes: reconstruct_path(&parents, node), cost });
+        }
+        // Stale entries, for which a cheaper way has since been found, are left in the heap instead of being updated
+        if costs.get(&node).map_or(false, |&known| cost > known) { continue; }
+
+        for neighbor in graph.neighbors(node) {
+            let potential_cost = cost + graph.weight(node, neighbor);
+            if costs.get(&neighbor).map_or(true, |&known| potential_cost < known) {
+                costs.insert(neighbor, potential_cost);
+                parents.insert(neighbor, node);
+                open.push(Candidate { estimate: potential_cost + heuristic(neighbor), cost: potential_cost, node: neighbor });
+            }
+        }
+    }
+
+    None
+}
+
+/// Cheapest path from `start` to the first node satisfying `is_target`, or `None` if there is none
+pub fn dijkstra<G, T>(graph: &G, start: G::Node, is_target: T) -> Option<Path<G::Node>>
+    where
+        G: Graph,
+        T: Fn(G::Node) -> bool {
+    a_star(graph, start, is_target, |_| 0)
+}
+
+/// Cost of the cheapest path from `start` to every node that can be reached from it, including `start` itself
+pub fn distances<G: Graph>(graph: &G, start: G::Node) -> HashMap<G::Node, u64> {
+    let mut costs = HashMap::new();
+    let mut open = BinaryHeap::new();
+    open.push(Candidate { estimate: 0, cost: 0, node: start });
+
+    while let Some(Candidate { cost, node, .. }) = open.pop() {
+        if costs.contains_key(&node) { continue; }
+        costs.insert(node, cost);
+
+        for neighbor in graph.neighbors(node) {
+            if !costs.contains_key(&neighbor) {
+                let potential_cost = cost + graph.weight(node, neighbor);
+                open.push(Candidate { estimate: potential_cost, cost: potential_cost, node: neighbor });
+            }
+        }
+    }
+
+    costs
+}
+
+/// Path with the fewest steps from `start` to the first node satisfying `is_target`, ignoring weights.
+/// The cost of the returned path is its number of steps.
+pub fn bfs<G, T>(graph: &G, start: G::Node, is_target: T) -> Option<Path<G::Node>>
+    where
+        G: Graph,
+        T: Fn(G::Node) -> bool {
+    let mut parents = HashMap::new();
+    let mut seen = HashSet::new();
+    let mut queue = VecDeque::new();
+    seen.insert(start);
+    queue.push_back(start);
+
+    while let Some(node) = queue.pop_front() {
+        if is_target(node) {
+            let nodes = reconstruct_path(&parents, node);
+            let cost = nodes.len() as u64 - 1;
+            return Some(Path { nodes, cost });
+        }
+        for neighbor in graph.neighbors(node) {
+            if seen.insert(neighbor) {
+                parents.insert(neighbor, node);
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    None
+}
+
+#[test]
+fn graph_search_test() {
+    /// Edges of `(from, to, weight)`
+    struct Edges(Vec<(u8, u8, u64)>);
+
+    impl Graph for Edges {
+        type Node = u8;
+
+        fn neighbors(&self, node: u8) -> Vec<u8> {
+            self.0.iter().filter(|e| e.0 == node).map(|e| e.1).collect()
+        }
+
+        fn weight(&self, from: u8, to: u8) -> u64 {
+            self.0.iter().find(|e| e.0 == from && e.1 == to).unwrap().2
+        }
+    }
+
+    // 0 -> 3 directly is the fewest steps, but going through 1 and 2 is cheaper
+    let graph = Edges(vec![(0, 1, 1), (1, 2, 1), (2, 3, 1), (0, 3, 10), (3, 4, 1)]);
+    assert_eq!(Some(Path { nodes: vec![0, 1, 2, 3, 4], cost: 4 }), dijkstra(&graph, 0, |n| n == 4));
+    assert_eq!(Some(Path { nodes: vec![0, 3, 4], cost: 2 }), bfs(&graph, 0, |n| n == 4));
+    assert_eq!(dijkstra(&graph, 0, |n| n == 4), a_star(&graph, 0, |n| n == 4, |n| 4 - n as u64));
+    assert_eq!(Some(Path { nodes: vec![2], cost: 0 }), dijkstra(&graph, 2, |n| n == 2));
+    assert_eq!(None, dijkstra(&graph, 4, |n| n == 0));
+    assert_eq!(None, bfs(&graph, 3, |n| n == 1));
+
+    let costs = distances(&graph, 1);
+    assert_eq!(4, costs.len());
+    assert_eq!((Some(&0), Some(&2), Some(&3), None), (costs.get(&1), costs.get(&3), costs.get(&4), costs.get(&0)));
+
+    // Admissible but inconsistent, so 1 is first reached the expensive way and has to be searched from again
+    let graph = Edges(vec![(0, 1, 4), (0, 2, 1), (2, 1, 1), (1, 3, 5)]);
+    assert_eq!(Some(Path { nodes: vec![0, 2, 1, 3], cost: 7 }), a_star(&graph, 0, |n| n == 3, |n| if n == 2 { 5 } else { 0 }));
+}
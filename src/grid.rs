@@ -0,0 +1,404 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+use crate::error::ParseError;
+use crate::point::Point;
+
+/// Offsets of the orthogonally adjacent positions, clockwise starting from up
+pub const NEIGHBOURS_4: [(i32, i32); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+/// Offsets of the orthogonally and diagonally adjacent positions, clockwise starting from up
+pub const NEIGHBOURS_8: [(i32, i32); 8] = [(0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0), (-1, -1)];
+
+/// Dense, rectangular grid indexed by `Point`s, with `(0, 0)` being the top-left corner
+/// and `y` increasing downwards. A grid without any columns or without any rows is empty,
+/// with both its width and height being 0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    /// Stored row by row
+    cells: Vec<T>
+}
+
+impl<T> Grid<T> {
+    /// Keeps the size of an empty grid consistent, as a width of 0 would otherwise leave `height` rows
+    /// that can't be told apart when going from an index to a point
+    fn with_cells(width: usize, height: usize, cells: Vec<T>) -> Self {
+        match width == 0 || height == 0 {
+            true => Grid { width: 0, height: 0, cells: Vec::new() },
+            false => Grid { width, height, cells }
+        }
+    }
+
+    pub fn new(width: usize, height: usize, fill: T) -> Self where T: Clone {
+        Grid::with_cells(width, height, vec![fill; width * height])
+    }
+
+    /// Creates a grid where each cell is the result of calling `f` with its position
+    pub fn from_fn<F>(width: usize, height: usize, mut f: F) -> Self where F: FnMut(Point<i32>) -> T {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point::new(x as i32, y as i32)))
+            .map(&mut f)
+            .collect();
+        Grid::with_cells(width, height, cells)
+    }
+
+    /// Returns `None` if the rows are not all the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+
+        Some(Grid::with_cells(width, height, rows.into_iter().flatten().collect()))
+    }
+
+    /// Parses a grid of characters, one row per line, converting each character with `f`.
+    /// `expected` describes the characters `f` accepts and is used for the error when it returns `None`.
+    pub fn parse_chars<F>(s: &str, expected: &str, mut f: F) -> Result<Self, ParseError> where F: FnMut(char) -> Option<T> {
+        let mut rows: Vec<Vec<T>> = Vec::new();
+        for (y, line) in s.lines().enumerate() {
+            let row = line.chars()
+                .enumerate()
+                .map(|(x, c)| f(c).ok_or_else(|| ParseError::unexpected(y + 1, x + 1, &c.to_string(), expected)))
+                .collect::<Result<Vec<T>, ParseError>>()?;
+            if let Some(width) = rows.first().map(Vec::len).filter(|&width| width != row.len()) {
+                return Err(ParseError::unexpected(y + 1, 1, line, &format!("a row {} characters long", width)));
+            }
+            rows.push(row);
+        }
+        // Rows are all the same length, so blank lines on their own make for no rows at all
+        if rows.first().map_or(true, Vec::is_empty) {
+            return Err(ParseError::missing(1, 1, "a row of the grid"));
+        }
+
+        Ok(Grid::from_rows(rows).expect("rows should all be the same length"))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point<i32>) -> bool {
+        self.index_of(point).is_some()
+    }
+
+    /// Position of `point` in row-by-row order, if it is within the grid
+    pub fn index_of(&self, point: Point<i32>) -> Option<usize> {
+        if point.x < 0 || point.y < 0 || point.x as usize >= self.width || point.y as usize >= self.height {
+            return None;
+        }
+
+        Some(point.y as usize * self.width + point.x as usize)
+    }
+
+    /// Inverse of [`Grid::index_of`]
+    ///
+    /// # Panics
+    /// Panics if `index` is outside of the grid
+    pub fn point_of(&self, index: usize) -> Point<i32> {
+        assert!(index < self.cells.len(), "index should be within the grid");
+        Point::new((index % self.width) as i32, (index / self.width) as i32)
+    }
+
+    pub fn get(&self, point: Point<i32>) -> Option<&T> {
+        self.index_of(point).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, point: Point<i32>) -> Option<&mut T> {
+        self.index_of(point).map(move |index| &mut self.cells[index])
+    }
+
+    /// # Panics
+    /// Panics if `y` is outside of the grid
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        // chunks panics on a size of 0, which is only possible for an empty grid, where there are no rows anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// # Panics
+    /// Panics if `x` is outside of the grid
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(x < self.width, "column should be within the grid");
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    /// Every position in the grid, row by row
+    pub fn points(&self) -> impl Iterator<Item = Point<i32>> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x as i32, y as i32)))
+    }
+
+    /// Every cell along with its position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Point<i32>, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    /// Positions adjacent to `point` that are within the grid, using the given offsets
+    pub fn neighbours<'a>(&'a self, point: Point<i32>, offsets: &'a [(i32, i32)]) -> impl Iterator<Item = Point<i32>> + 'a {
+        offsets.iter()
+            .map(move |&offset| point.add_tuple(offset))
+            .filter(move |&neighbour| self.contains(neighbour))
+    }
+
+    /// Orthogonally adjacent positions within the grid
+    pub fn neighbours_4(&self, point: Point<i32>) -> impl Iterator<Item = Point<i32>> + '_ {
+        self.neighbours(point, &NEIGHBOURS_4)
+    }
+
+    /// Orthogonally and diagonally adjacent positions within the grid
+    pub fn neighbours_8(&self, point: Point<i32>) -> impl Iterator<Item = Point<i32>> + '_ {
+        self.neighbours(point, &NEIGHBOURS_8)
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U> where F: FnMut(&T) -> U {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+}
+
+/// # Panics
+/// Panics if the point is outside of the grid
+impl<T> Index<Point<i32>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point<i32>) -> &Self::Output {
+        self.get(point).expect("point should be within the grid")
+    }
+}
+
+/// # Panics
+/// Panics if the point is outside of the grid
+impl<T> IndexMut<Point<i32>> for Grid<T> {
+    fn index_mut(&mut self, point: Point<i32>) -> &mut Self::Output {
+        self.get_mut(point).expect("point should be within the grid")
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse_chars(s, "a character", Some)
+    }
+}
+
+/// Writes each row on its own line, with every cell written next to each other
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Grid that only stores the positions that have been set, so it can grow in any direction
+/// without its size being known up front
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point<i64>, T>,
+    /// Top-left and bottom-right corners of the occupied region, inclusive
+    bounds: Option<(Point<i64>, Point<i64>)>
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        SparseGrid { cells: HashMap::new(), bounds: None }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, point: Point<i64>) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    pub fn get(&self, point: Point<i64>) -> Option<&T> {
+        self.cells.get(&point)
+    }
+
+    pub fn get_mut(&mut self, point: Point<i64>) -> Option<&mut T> {
+        self.cells.get_mut(&point)
+    }
+
+    /// Sets the value at `point`, returning the value that was there before
+    pub fn insert(&mut self, point: Point<i64>, value: T) -> Option<T> {
+        self.bounds = Some(match self.bounds {
+            None => (point, point),
+            Some((min, max)) => (
+                Point::new(min.x.min(point.x), min.y.min(point.y)),
+                Point::new(max.x.max(point.x), max.y.max(point.y))
+            )
+        });
+        self.cells.insert(point, value)
+    }
+
+    pub fn remove(&mut self, point: Point<i64>) -> Option<T> {
+        let removed = self.cells.remove(&point);
+        if let Some((min, max)) = self.bounds {
+            // Only shrinks if the point was on the edge of the occupied region
+            if removed.is_some() && (point.x == min.x || point.x == max.x || point.y == min.y || point.y == max.y) {
+                self.bounds = self.calc_bounds();
+            }
+        }
+
+        removed
+    }
+
+    fn calc_bounds(&self) -> Option<(Point<i64>, Point<i64>)> {
+        let mut points = self.cells.keys();
+        let first = *points.next()?;
+        Some(points.fold((first, first), |(min, max), point| (
+            Point::new(min.x.min(point.x), min.y.min(point.y)),
+            Point::new(max.x.max(point.x), max.y.max(point.y))
+        )))
+    }
+
+    /// Top-left and bottom-right corners of the occupied region, inclusive, or `None` if nothing is set
+    pub fn bounds(&self) -> Option<(Point<i64>, Point<i64>)> {
+        self.bounds
+    }
+
+    /// Positions that have been set, in no particular order
+    pub fn points(&self) -> impl Iterator<Item = Point<i64>> + '_ {
+        self.cells.keys().copied()
+    }
+
+    /// Every set value along with its position, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (Point<i64>, &T)> {
+        self.cells.iter().map(|(&point, value)| (point, value))
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.values()
+    }
+
+    /// Copies the occupied region into a dense grid, using `fill` for positions that are not set.
+    /// The top-left corner of the region is at `(0, 0)` in the returned grid.
+    pub fn to_grid(&self, fill: T) -> Grid<T> where T: Clone {
+        let (min, max) = match self.bounds {
+            Some(bounds) => bounds,
+            None => return Grid::new(0, 0, fill)
+        };
+        let width = (max.x - min.x + 1) as usize;
+        let height = (max.y - min.y + 1) as usize;
+        Grid::from_fn(width, height, |offset| {
+            let point = Point::new(min.x + offset.x as i64, min.y + offset.y as i64);
+            self.cells.get(&point).cloned().unwrap_or_else(|| fill.clone())
+        })
+    }
+
+    /// Writes out the occupied region a row at a time, using `empty` for positions that are not set
+    pub fn render(&self, empty: char) -> String where T: fmt::Display {
+        let mut s = String::new();
+        if let Some((min, max)) = self.bounds {
+            for y in min.y..=max.y {
+                for x in min.x..=max.x {
+                    match self.cells.get(&Point::new(x, y)) {
+                        Some(value) => s += &value.to_string(),
+                        None => s.push(empty)
+                    }
+                }
+                s.push('\n');
+            }
+        }
+
+        s
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<(Point<i64>, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point<i64>, T)>>(iter: I) -> Self {
+        let mut grid = SparseGrid::new();
+        for (point, value) in iter {
+            grid.insert(point, value);
+        }
+        grid
+    }
+}
+
+#[test]
+fn grid_test() {
+    let grid = "abc\ndef".parse::<Grid<char>>().unwrap();
+    assert_eq!((3, 2), (grid.width(), grid.height()));
+    assert_eq!('f', grid[Point::new(2, 1)]);
+    assert_eq!(None, grid.get(Point::new(3, 0)));
+    assert_eq!(None, grid.get(Point::new(0, -1)));
+    assert_eq!(vec!['d', 'e', 'f'], grid.row(1));
+    assert_eq!(vec![&'b', &'e'], grid.column(1).collect::<Vec<&char>>());
+    assert_eq!(vec![Point::new(1, 0), Point::new(0, 1)], grid.neighbours_4(Point::new(0, 0)).collect::<Vec<Point<i32>>>());
+    assert_eq!(5, grid.neighbours_8(Point::new(1, 0)).count());
+    assert_eq!(Some(4), grid.index_of(Point::new(1, 1)));
+    assert_eq!(Point::new(1, 1), grid.point_of(4));
+    assert_eq!("abc\ndef\n", grid.to_string());
+    assert_eq!("ABC\nDEF\n", grid.map(char::to_ascii_uppercase).to_string());
+
+    assert_eq!(Err(ParseError::unexpected(2, 1, "de", "a row 3 characters long")), "abc\nde".parse::<Grid<char>>());
+    assert_eq!(Err(ParseError::unexpected(1, 2, "x", "a digit")), Grid::parse_chars("1x", "a digit", |c| c.to_digit(10)));
+}
+
+#[test]
+fn grid_empty_test() {
+    let empty = Grid::new(0, 0, '.');
+    for grid in [Grid::new(0, 3, '.'), Grid::from_fn(4, 0, |_| '.'), Grid::from_rows(vec![Vec::new(), Vec::new()]).unwrap()] {
+        assert_eq!(empty, grid);
+        assert_eq!((0, 0), (grid.width(), grid.height()));
+        assert_eq!(0, grid.rows().count());
+        assert_eq!(0, grid.points().count());
+        assert_eq!(None, grid.index_of(Point::new(0, 0)));
+        assert_eq!("", grid.to_string());
+    }
+    assert!(std::panic::catch_unwind(|| empty.point_of(0)).is_err());
+    for blank in ["", "\n", "\n\n"] {
+        assert_eq!(Err(ParseError::missing(1, 1, "a row of the grid")), blank.parse::<Grid<char>>());
+    }
+}
+
+#[test]
+fn sparse_grid_test() {
+    let mut grid = SparseGrid::new();
+    assert_eq!(None, grid.bounds());
+    grid.insert(Point::new(0, 0), 'a');
+    grid.insert(Point::new(-2, 1), 'b');
+    grid.insert(Point::new(1, -1), 'c');
+    assert_eq!(Some((Point::new(-2, -1), Point::new(1, 1))), grid.bounds());
+    assert_eq!("...c\n..a.\nb...\n", grid.render('.'));
+    assert_eq!(Some(&'b'), grid.get(Point::new(-2, 1)));
+    assert_eq!(None, grid.get(Point::new(100, 100)));
+
+    assert_eq!(Some('b'), grid.remove(Point::new(-2, 1)));
+    assert_eq!(Some((Point::new(0, -1), Point::new(1, 0))), grid.bounds());
+    assert_eq!(".c\na.\n", grid.to_grid('.').to_string());
+}
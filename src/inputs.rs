@@ -0,0 +1,141 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Environment variable that overrides the directory puzzle inputs are read from
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+/// Directory puzzle inputs are read from when [`INPUT_DIR_VAR`] is not set, relative to the working directory
+pub const DEFAULT_INPUT_DIR: &str = "input";
+
+/// Error for puzzle inputs that could not be found or read
+#[derive(Debug)]
+pub enum InputError {
+    /// No file exists at `path`
+    Missing { path: PathBuf },
+    /// The input exists but could not be read
+    Io { source: String, error: io::Error }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Missing { path } => write!(
+                f,
+                "no input found at '{}'; pass a path with --input, use '--input -' to read from stdin or set {} to the directory containing your inputs",
+                path.display(),
+                INPUT_DIR_VAR
+            ),
+            InputError::Io { source, error } => write!(f, "could not read {}: {}", source, error)
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// Where a puzzle input is read from
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Source {
+    File(PathBuf),
+    Stdin
+}
+
+impl Source {
+    /// Interprets a command line argument, where `-` means stdin
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => Source::Stdin,
+            path => Source::File(PathBuf::from(path))
+        }
+    }
+
+    /// The default input file of `day`, i.e. `dayN` within [`input_dir`]
+    pub fn for_day(day: u8) -> Self {
+        Source::File(day_path(&input_dir(), day))
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        match self {
+            Source::File(path) => std::fs::read_to_string(path).map_err(|error| match error.kind() {
+                io::ErrorKind::NotFound => InputError::Missing { path: path.clone() },
+                _ => InputError::Io { source: format!("'{}'", path.display()), error }
+            }),
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)
+                    .map_err(|error| InputError::Io { source: "stdin".to_string(), error })?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::File(path) => write!(f, "{}", path.display()),
+            Source::Stdin => write!(f, "stdin")
+        }
+    }
+}
+
+/// Directory containing the puzzle inputs, taken from [`INPUT_DIR_VAR`] if it is set
+pub fn input_dir() -> PathBuf {
+    match std::env::var_os(INPUT_DIR_VAR) {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => PathBuf::from(DEFAULT_INPUT_DIR)
+    }
+}
+
+pub fn day_path(dir: &Path, day: u8) -> PathBuf {
+    dir.join(format!("day{}", day))
+}
+
+/// Reads the input of `day` from [`input_dir`]
+pub fn read_day(day: u8) -> Result<String, InputError> {
+    Source::for_day(day).read()
+}
+
+/// Reads the input of `day`, printing the error and exiting if it cannot be read.
+/// Meant for the individual day binaries.
+pub fn read_day_or_exit(day: u8) -> String {
+    read_day(day).unwrap_or_else(|e| {
+        eprintln!("error: {}", e);
+        std::process::exit(1);
+    })
+}
+
+/// Remembers every input read so that each file is only read once,
+/// e.g. when the same days are run repeatedly
+#[derive(Debug, Default)]
+pub struct InputCache {
+    inputs: HashMap<Source, String>
+}
+
+impl InputCache {
+    pub fn new() -> Self {
+        InputCache { inputs: HashMap::new() }
+    }
+
+    /// Reads `source` the first time it is requested, returning the stored input afterwards.
+    /// Failed reads are not stored.
+    pub fn get(&mut self, source: &Source) -> Result<&str, InputError> {
+        if !self.inputs.contains_key(source) {
+            let input = source.read()?;
+            self.inputs.insert(source.clone(), input);
+        }
+
+        Ok(&self.inputs[source])
+    }
+}
+
+#[test]
+fn input_source_test() {
+    assert_eq!(Source::Stdin, Source::from_arg("-"));
+    assert_eq!(Source::File(PathBuf::from("inputs/day3")), Source::from_arg("inputs/day3"));
+    assert_eq!(PathBuf::from("inputs").join("day12"), day_path(Path::new("inputs"), 12));
+
+    let missing = Source::from_arg("this/file/does/not/exist");
+    assert!(matches!(missing.read(), Err(InputError::Missing { .. })));
+    assert!(matches!(InputCache::new().get(&missing), Err(InputError::Missing { .. })));
+}
@@ -0,0 +1,383 @@
+use std::fmt;
+use std::str::FromStr;
+use crate::error::ParseError;
+
+/// Minimal JSON value, written out compactly through its `Display` implementation and read back with `FromStr`
+#[derive(Debug, Clone, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Integer(i64),
+    Float(f64),
+    String(String),
+    Array(Vec<Json>),
+    /// Keys are written in the order they were added
+    Object(Vec<(String, Json)>)
+}
+
+impl Json {
+    /// Builds an object from `(key, value)` pairs
+    pub fn object<'a, I>(fields: I) -> Self where I: IntoIterator<Item = (&'a str, Json)> {
+        Json::Object(fields.into_iter().map(|(key, value)| (key.to_string(), value)).collect())
+    }
+}
+
+impl From<bool> for Json {
+    fn from(value: bool) -> Self {
+        Json::Bool(value)
+    }
+}
+
+impl From<i64> for Json {
+    fn from(value: i64) -> Self {
+        Json::Integer(value)
+    }
+}
+
+impl From<u8> for Json {
+    fn from(value: u8) -> Self {
+        Json::Integer(value as i64)
+    }
+}
+
+/// Values too large for a [`Json::Integer`] become the nearest [`Json::Float`]
+impl From<usize> for Json {
+    fn from(value: usize) -> Self {
+        i64::try_from(value).map_or_else(|_| Json::Float(value as f64), Json::Integer)
+    }
+}
+
+impl From<f64> for Json {
+    fn from(value: f64) -> Self {
+        Json::Float(value)
+    }
+}
+
+impl From<&str> for Json {
+    fn from(value: &str) -> Self {
+        Json::String(value.to_string())
+    }
+}
+
+impl From<String> for Json {
+    fn from(value: String) -> Self {
+        Json::String(value)
+    }
+}
+
+impl<T: Into<Json>> From<Vec<T>> for Json {
+    fn from(value: Vec<T>) -> Self {
+        Json::Array(value.into_iter().map(Into::into).collect())
+    }
+}
+
+fn write_string(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    write!(f, "\"")?;
+    for c in s.chars() {
+        match c {
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            '\n' => write!(f, "\\n")?,
+            '\r' => write!(f, "\\r")?,
+            '\t' => write!(f, "\\t")?,
+            c if c.is_control() => write!(f, "\\u{:04x}", c as u32)?,
+            c => write!(f, "{}", c)?
+        }
+    }
+    write!(f, "\"")
+}
+
+impl fmt::Display for Json {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Json::Null => write!(f, "null"),
+            Json::Bool(b) => write!(f, "{}", b),
+            Json::Integer(n) => write!(f, "{}", n),
+            // JSON has no representation for NaN or infinity
+            Json::Float(n) if !n.is_finite() => write!(f, "null"),
+            Json::Float(n) => write!(f, "{}", n),
+            Json::String(s) => write_string(f, s),
+            Json::Array(values) => {
+                write!(f, "[")?;
+                for (index, value) in values.iter().enumerate() {
+                    if index > 0 { write!(f, ",")?; }
+                    write!(f, "{}", value)?;
+                }
+                write!(f, "]")
+            }
+            Json::Object(fields) => {
+                write!(f, "{{")?;
+                for (index, (key, value)) in fields.iter().enumerate() {
+                    if index > 0 { write!(f, ",")?; }
+                    write_string(f, key)?;
+                    write!(f, ":{}", value)?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+/// Recursive descent over a JSON document, keeping track of the byte position reached
+struct JsonParser<'a> {
+    s: &'a str,
+    position: usize
+}
+
+impl<'a> JsonParser<'a> {
+    fn peek(&self) -> Option<char> {
+        self.s[self.position..].chars().next()
+    }
+
+    /// 1-based line and column of the current position, as documents can span several lines
+    fn line_column(&self) -> (usize, usize) {
+        let before = &self.s[..self.position];
+        let line = before.matches('\n').count() + 1;
+        let column = before.rsplit('\n').next().unwrap_or(before).chars().count() + 1;
+        (line, column)
+    }
+
+    /// Error for the character at the current position not being `expected`
+    fn unexpected(&self, expected: &str) -> ParseError {
+        let (line, column) = self.line_column();
+        match self.peek() {
+            Some(c) => ParseError::unexpected(line, column, &c.to_string(), expected),
+            None => ParseError::missing(line, column, expected)
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.s[self.position..];
+        self.position += rest.len() - rest.trim_start_matches([' ', '\t', '\n', '\r']).len();
+    }
+
+    fn expect(&mut self, c: char, expected: &str) -> Result<(), ParseError> {
+        match self.peek() {
+            Some(found) if found == c => {
+                self.position += c.len_utf8();
+                Ok(())
+            },
+            _ => Err(self.unexpected(expected))
+        }
+    }
+
+    fn value(&mut self) -> Result<Json, ParseError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('n') => self.literal("null", Json::Null),
+            Some('t') => self.literal("true", Json::Bool(true)),
+            Some('f') => self.literal("false", Json::Bool(false)),
+            Some('"') => self.string().map(Json::String),
+            Some('[') => self.array(),
+            Some('{') => self.object(),
+            Some('-' | '0'..='9') => self.number(),
+            _ => Err(self.unexpected("a JSON value"))
+        }
+    }
+
+    fn literal(&mut self, word: &str, value: Json) -> Result<Json, ParseError> {
+        if !self.s[self.position..].starts_with(word) {
+            return Err(self.unexpected(&format!("'{}'", word)));
+        }
+        self.position += word.len();
+        Ok(value)
+    }
+
+    fn digits(&mut self) -> usize {
+        let rest = &self.s[self.position..];
+        let count = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+        self.position += count;
+        count
+    }
+
+    fn number(&mut self) -> Result<Json, ParseError> {
+        let (line, column) = self.line_column();
+        let start = self.position;
+        let mut integer = true;
+        if self.peek() == Some('-') { self.position += 1; }
+        if self.digits() == 0 { return Err(self.unexpected("a digit")); }
+        if self.peek() == Some('.') {
+            self.position += 1;
+            integer = false;
+            if self.digits() == 0 { return Err(self.unexpected("a digit")); }
+        }
+        if let Some('e' | 'E') = self.peek() {
+            self.position += 1;
+            integer = false;
+            if let Some('+' | '-') = self.peek() { self.position += 1; }
+            if self.digits() == 0 { return Err(self.unexpected("a digit")); }
+        }
+        let text = &self.s[start..self.position];
+        let invalid = || ParseError::invalid_number(line, column, text);
+
+        match integer {
+            true => text.parse::<i64>().map(Json::Integer).map_err(|_| invalid()),
+            false => text.parse::<f64>().map(Json::Float).map_err(|_| invalid())
+        }
+    }
+
+    /// Four hex digits of a `\u` escape
+    fn hex(&mut self) -> Result<u32, ParseError> {
+        let digits = self.s.get(self.position..self.position + 4)
+            .filter(|digits| digits.chars().all(|c| c.is_ascii_hexdigit()))
+            .ok_or_else(|| self.unexpected("four hex digits"))?;
+        self.position += 4;
+        Ok(u32::from_str_radix(digits, 16).expect("digits should be hex"))
+    }
+
+    fn string(&mut self) -> Result<String, ParseError> {
+        self.expect('"', "'\"'")?;
+        let mut result = String::new();
+        loop {
+            let c = self.peek().ok_or_else(|| self.unexpected("'\"'"))?;
+            match c {
+                '"' => {
+                    self.position += 1;
+                    return Ok(result);
+                },
+                '\\' => {
+                    self.position += 1;
+                    let escape = self.peek().ok_or_else(|| self.unexpected("an escape sequence"))?;
+                    let escaped = match escape {
+                        '"' | '\\' | '/' => escape,
+                        'b' => '\u{8}',
+                        'f' => '\u{c}',
+                        'n' => '\n',
+                        'r' => '\r',
+                        't' => '\t',
+                        'u' => {
+                            self.position += 1;
+                            let mut code = self.hex()?;
+                            // Characters outside the basic plane are written as a surrogate pair
+                            if (0xD800..0xDC00).contains(&code) && self.s[self.position..].starts_with("\\u") {
+                                self.position += 2;
+                                let low = self.hex()?;
+                                if !(0xDC00..0xE000).contains(&low) {
+                                    self.position -= 4;
+                                    return Err(self.unexpected("a low surrogate"));
+                                }
+                                code = 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00);
+                            }
+                            result.push(char::from_u32(code).ok_or_else(|| {
+                                self.position -= 4;
+                                self.unexpected("a valid unicode escape")
+                            })?);
+                            continue;
+                        },
+                        _ => return Err(self.unexpected("an escape sequence"))
+                    };
+                    self.position += 1;
+                    result.push(escaped);
+                },
+                c if c.is_control() => return Err(self.unexpected("an escaped control character")),
+                c => {
+                    self.position += c.len_utf8();
+                    result.push(c);
+                }
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<Json, ParseError> {
+        self.expect('[', "'['")?;
+        let mut values = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(']') {
+            self.position += 1;
+            return Ok(Json::Array(values));
+        }
+        loop {
+            values.push(self.value()?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => self.position += 1,
+                Some(']') => {
+                    self.position += 1;
+                    return Ok(Json::Array(values));
+                },
+                _ => return Err(self.unexpected("',' or ']'"))
+            }
+        }
+    }
+
+    fn object(&mut self) -> Result<Json, ParseError> {
+        self.expect('{', "'{'")?;
+        let mut fields = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some('}') {
+            self.position += 1;
+            return Ok(Json::Object(fields));
+        }
+        loop {
+            self.skip_whitespace();
+            if self.peek() != Some('"') {
+                return Err(self.unexpected("a string key"));
+            }
+            let key = self.string()?;
+            self.skip_whitespace();
+            self.expect(':', "':'")?;
+            fields.push((key, self.value()?));
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => self.position += 1,
+                Some('}') => {
+                    self.position += 1;
+                    return Ok(Json::Object(fields));
+                },
+                _ => return Err(self.unexpected("',' or '}'"))
+            }
+        }
+    }
+}
+
+/// Parses a single JSON value, surrounded by any amount of whitespace.
+/// Numbers without a fraction or exponent become [`Json::Integer`] and have to fit in an `i64`.
+impl FromStr for Json {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = JsonParser { s, position: 0 };
+        let value = parser.value()?;
+        parser.skip_whitespace();
+        if parser.position < s.len() {
+            let (line, column) = parser.line_column();
+            return Err(ParseError::unexpected(line, column, &s[parser.position..], "the end of the JSON"));
+        }
+
+        Ok(value)
+    }
+}
+
+#[test]
+fn json_display_test() {
+    let value = Json::object([
+        ("day", Json::from(10u8)),
+        ("answer", Json::from("x.\n\"\\\"")),
+        ("elapsed_ms", Json::from(1.5)),
+        ("parts", Json::from(vec![1usize, 2])),
+        ("missing", Json::Null)
+    ]);
+    assert_eq!(r#"{"day":10,"answer":"x.\n\"\\\"","elapsed_ms":1.5,"parts":[1,2],"missing":null}"#, value.to_string());
+    assert_eq!(Json::Float(usize::MAX as f64), Json::from(usize::MAX));
+}
+
+#[test]
+fn json_parse_test() {
+    let text = r#"{"day":10,"answer":"x.\n\"\\\"","elapsed_ms":1.5,"parts":[1,2],"missing":null}"#;
+    assert_eq!(text, text.parse::<Json>().unwrap().to_string());
+
+    let value = " {\n  \"a\": [true, false, -12, 3e2, \"\\u00e9\\ud83d\\ude00\\/\"],\n  \"b\": {}\n} ".parse::<Json>().unwrap();
+    assert_eq!(Json::object([
+        ("a", Json::Array(vec![Json::Bool(true), Json::Bool(false), Json::Integer(-12), Json::Float(300.0), Json::from("é😀/")])),
+        ("b", Json::Object(Vec::new()))
+    ]), value);
+
+    assert_eq!(Err(ParseError::unexpected(2, 7, "]", "a JSON value")), "[1,\n  [2, ]]".parse::<Json>());
+    assert_eq!(Err(ParseError::missing(1, 4, "',' or ']'")), "[1 ".parse::<Json>());
+    assert_eq!(Err(ParseError::unexpected(1, 2, "'", "a JSON value")), "['a']".parse::<Json>());
+    assert_eq!(Err(ParseError::unexpected(1, 3, "x", "a string key")), "{ x: 1 }".parse::<Json>());
+    assert_eq!(Err(ParseError::unexpected(1, 3, "2", "the end of the JSON")), "1 2".parse::<Json>());
+    assert_eq!(Err(ParseError::invalid_number(1, 1, "99999999999999999999")), "99999999999999999999".parse::<Json>());
+    assert_eq!(Err(ParseError::unexpected(1, 1, "n", "'null'")), "nul".parse::<Json>());
+    assert_eq!(Err(ParseError::unexpected(1, 10, "0", "a low surrogate")), r#""\ud83d\u0041""#.parse::<Json>());
+}
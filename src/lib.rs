@@ -1,28 +1,98 @@
+pub mod answers;
+pub mod bench;
+pub mod error;
+pub mod graph;
+pub mod grid;
+pub mod inputs;
+pub mod json;
+pub mod scan;
+pub mod schedule;
+pub mod solver;
+pub mod days;
+
 pub mod utils {
-    pub fn split_lines_group(s: &str, n: usize) -> Vec<String> {
-        let lines = s.lines().collect::<Vec<&str>>();
-        let mut result = Vec::new();
-        for chunk in lines.chunks(n) {
-            let to_add = chunk.iter().fold(String::new(), |a, e| a + e + "\n");
-            result.push(to_add);
+    use std::collections::VecDeque;
+    use std::ops::Range;
+    use std::thread;
+    use num::{BigUint, ToPrimitive, Zero};
+
+    /// Blocks of lines separated by one or more blank lines, where lines that are only whitespace count as blank.
+    /// Each block is a slice of `input` without its final line break, paired with the 0-based index of its first line
+    /// so that errors found within it can be moved with [`ParseError::offset_lines`](crate::error::ParseError::offset_lines).
+    pub fn paragraphs(input: &str) -> Paragraphs<'_> {
+        Paragraphs { rest: input, line: 0 }
+    }
+
+    pub struct Paragraphs<'a> {
+        rest: &'a str,
+        /// Index of the first line of `rest`
+        line: usize
+    }
+
+    impl<'a> Iterator for Paragraphs<'a> {
+        type Item = (usize, &'a str);
+
+        fn next(&mut self) -> Option<Self::Item> {
+            loop {
+                let first = self.rest.split_inclusive('\n').next()?;
+                if !first.trim().is_empty() { break; }
+                self.rest = &self.rest[first.len()..];
+                self.line += 1;
+            }
+            let start = self.line;
+            let mut end = 0;
+            for line in self.rest.split_inclusive('\n').take_while(|line| !line.trim().is_empty()) {
+                end += line.len();
+                self.line += 1;
+            }
+            let paragraph = &self.rest[..end];
+            self.rest = &self.rest[end..];
+
+            Some((start, paragraph.strip_suffix('\n').map_or(paragraph, |p| p.strip_suffix('\r').unwrap_or(p))))
         }
-    
-        result
     }
 
-    /// Very very rudimentary implementation of getting all
-    /// the permutations of a given list of data (currently only implemented for Vectors)
-    /// 
-    /// # Panics
-    /// Panics if factorial of length of permutable is greater than `usize`
-    pub trait Permutable<'a, T> {
-        type Item;
-        fn permutations(&'a self) -> Permutations<Self::Item>;
+    /// Every permutation of a collection, in lexicographic order of the positions of its items,
+    /// i.e. starting with the items in their original order
+    pub trait Permutable<T> {
+        /// Every item of the collection, in order
+        fn references(&self) -> Vec<&T>;
+
+        fn permutations(&self) -> Permutations<'_, T> {
+            Permutations::new(self.references())
+        }
+
+        /// Every ordered selection of `k` distinct items, in lexicographic order of their positions
+        fn permutations_k(&self, k: usize) -> KPermutations<'_, T> {
+            KPermutations::new(self.references(), k)
+        }
+
+        /// Every selection of `k` distinct items, keeping them in their original order
+        fn combinations(&self, k: usize) -> Combinations<'_, T> {
+            Combinations::new(self.references(), k)
+        }
+
+        /// Every selection of `k` items where each item can be picked more than once, keeping them in their original order
+        fn combinations_with_replacement(&self, k: usize) -> CombinationsWithReplacement<'_, T> {
+            CombinationsWithReplacement::new(self.references(), k)
+        }
+
+        /// Every subset, from the smallest to the largest, with each size in the order of `combinations`
+        fn powerset(&self) -> Powerset<'_, T> {
+            Powerset::new(self.references())
+        }
     }
 
-    pub struct Permutations<'a, C> {
-        permutation_number: Option<usize>,
-        collection: &'a C
+    /// Steps through the permutations in place, so getting each one takes amortised O(1) time
+    /// apart from copying it out
+    pub struct Permutations<'a, T> {
+        current: Vec<&'a T>,
+        /// Original positions of the items in `current`
+        indices: Vec<usize>,
+        started: bool,
+        finished: bool,
+        /// Number of permutations not yet yielded, if it fits in a `usize`
+        remaining: Option<usize>
     }
 
     /// Returns none if `n!` > `usize::MAX`;
@@ -37,20 +107,47 @@ pub mod utils {
         Some(r)
     }
 
+    /// Number of ways to choose `k` of `n` items, or none if it is greater than `usize::MAX`
+    pub fn binomial(n: usize, k: usize) -> Option<usize> {
+        if k > n { return Some(0); }
+        let k = k.min(n - k);
+        let mut r: usize = 1;
+        for i in 0..k {
+            // Every step is itself a binomial, so it fits if the result does
+            let next = r as u128 * (n - i) as u128 / (i + 1) as u128;
+            r = usize::try_from(next).ok()?;
+        }
+        Some(r)
+    }
+
+    /// Number of ordered selections of `k` of `n` items, `n! / (n - k)!`, or none if it is greater than `usize::MAX`
+    fn permutations_count(n: usize, k: usize) -> Option<usize> {
+        if k > n { return Some(0); }
+        (n - k + 1..=n).try_fold(1usize, |r, i| r.checked_mul(i))
+    }
+
+    /// Exact hint from the number of items an iterator has left, if it is known to fit in a `usize`
+    fn exact_size_hint(remaining: Option<usize>) -> (usize, Option<usize>) {
+        match remaining {
+            Some(n) => (n, Some(n)),
+            None => (usize::MAX, None)
+        }
+    }
+
     /// Transforms an integer `n` into a [factoradic](https://en.wikipedia.org/wiki/Factorial_number_system)
     /// with given `radix`
     /// 
     /// # Examples
     /// ```
     /// pub use advent_of_code2022::utils::to_factoradic;
-    /// assert_eq!(to_factoradic(463, 6), vec![0, 1, 0, 1, 4, 3]);
+    /// assert_eq!(to_factoradic(463, 6), vec![3, 4, 1, 0, 1, 0]);
     /// ```
     pub fn to_factoradic(n: usize, radix: usize) -> Vec<usize> {
         let mut result = Vec::new();
         let mut i = n;
         for divisor in 1..=radix {
             result.push(i % divisor);
-            i = i / divisor;
+            i /= divisor;
         }
         result.reverse();
 
@@ -68,6 +165,15 @@ pub mod utils {
 
         result
     }
+    #[test]
+    fn paragraphs_test() {
+        let input = "a\nb\n\n\nc\n  \t\nd\r\ne\r\n\r\n\n";
+        assert_eq!(vec![(0, "a\nb"), (4, "c"), (6, "d\r\ne")], paragraphs(input).collect::<Vec<(usize, &str)>>());
+        assert_eq!(vec![(2, "x")], paragraphs("\n\nx").collect::<Vec<(usize, &str)>>());
+        assert_eq!(None, paragraphs(" \n\n").next());
+        assert_eq!(None, paragraphs("").next());
+    }
+
     #[test]
     fn factoradic_as_permutation_test() {
         let decimal = 2982;
@@ -77,54 +183,409 @@ pub mod utils {
         let permutation = factoradic_as_permutation(&factoradic);
         assert_eq!(permutation, vec![4, 0, 6, 2, 1, 3, 5]);
     }
-    /// Wrapper around `to_factoradic` and `factoradic_as_permutation`
-    fn permutation_indices(n: usize, radix: usize) -> Vec<usize> {
-        factoradic_as_permutation(&to_factoradic(n, radix))
+
+    /// Position of `permutation`, a permutation of `0..permutation.len()`, in lexicographic order,
+    /// the inverse of [`unrank`]
+    ///
+    /// # Panics
+    /// Panics if the position is greater than `usize::MAX`, which can only happen for lengths over 20; use [`rank_big`] for those
+    pub fn rank(permutation: &[usize]) -> usize {
+        rank_big(permutation).to_usize().expect("rank should fit in a usize")
+    }
+
+    /// Permutation of `0..len` at position `n` in lexicographic order, so `unrank(0, len)` is `0..len` in order
+    ///
+    /// # Panics
+    /// Panics if `n` is not less than `len!`
+    pub fn unrank(n: usize, len: usize) -> Vec<usize> {
+        assert!(factorial(len).map_or(true, |count| n < count), "there should be more than {} permutations of {} items", n, len);
+        factoradic_as_permutation(&to_factoradic(n, len))
+    }
+
+    /// Same as [`rank`], for permutations of any length
+    pub fn rank_big(permutation: &[usize]) -> BigUint {
+        // Each digit of the factoradic is the number of later items that are smaller
+        permutation.iter().enumerate().fold(BigUint::zero(), |rank, (i, &item)| {
+            let smaller_after = permutation[i + 1..].iter().filter(|&&later| later < item).count();
+            rank * (permutation.len() - i) + smaller_after
+        })
     }
 
-    impl<'a, C> Permutations<'a, C> {
-        fn new(collection: &'a C) -> Self {
-            Permutations { permutation_number: Some(0), collection }
+    /// Same as [`unrank`], for permutations of any length
+    ///
+    /// # Panics
+    /// Panics if `n` is not less than `len!`
+    pub fn unrank_big(n: &BigUint, len: usize) -> Vec<usize> {
+        assert!(n < &factorial_big(len), "there should be more than {} permutations of {} items", n, len);
+        let mut digits = Vec::with_capacity(len);
+        let mut rest = n.clone();
+        for divisor in 1..=len {
+            digits.push((&rest % divisor).to_usize().expect("digit should be less than the divisor"));
+            rest /= divisor;
         }
+        digits.reverse();
+
+        factoradic_as_permutation(&digits)
     }
-    
-    impl<'a, T> Iterator for Permutations<'a, Vec<T>> {
+
+    pub fn factorial_big(n: usize) -> BigUint {
+        (1..=n).fold(BigUint::from(1u8), |r, i| r * i)
+    }
+
+    /// Positions to swap to reach the next permutation in lexicographic order, after which everything
+    /// past the first has to be reversed, or `None` if `items` are already in the last permutation
+    fn next_swap<T: Ord>(items: &[T]) -> Option<(usize, usize)> {
+        // Longest non-increasing suffix can't be rearranged into anything later on its own
+        let pivot = (1..items.len()).rev().find(|&i| items[i - 1] < items[i])? - 1;
+        let successor = (pivot + 1..items.len()).rev().find(|&i| items[i] > items[pivot])?;
+
+        Some((pivot, successor))
+    }
+
+    /// Rearranges `items` into the next permutation in lexicographic order, returning `false` and
+    /// leaving them sorted if they were already in the last permutation. Equal items are not told apart,
+    /// so each distinct arrangement is only reached once.
+    pub fn next_permutation<T: Ord>(items: &mut [T]) -> bool {
+        match next_swap(items) {
+            Some((pivot, successor)) => {
+                items.swap(pivot, successor);
+                items[pivot + 1..].reverse();
+                true
+            },
+            None => {
+                items.reverse();
+                false
+            }
+        }
+    }
+
+    impl<'a, T> Permutations<'a, T> {
+        fn new<I: IntoIterator<Item = &'a T>>(items: I) -> Self {
+            let current = items.into_iter().collect::<Vec<&T>>();
+            let indices = (0..current.len()).collect();
+            let remaining = factorial(current.len());
+            Permutations { current, indices, started: false, finished: false, remaining }
+        }
+
+        /// Moves on to the next permutation and borrows it, without copying it out like `next` does
+        pub fn advance(&mut self) -> Option<&[&'a T]> {
+            if self.finished { return None; }
+            if self.started {
+                // Same steps as `next_permutation`, mirrored onto the items
+                match next_swap(&self.indices) {
+                    Some((pivot, successor)) => {
+                        self.indices.swap(pivot, successor);
+                        self.indices[pivot + 1..].reverse();
+                        self.current.swap(pivot, successor);
+                        self.current[pivot + 1..].reverse();
+                    },
+                    None => {
+                        self.finished = true;
+                        self.remaining = Some(0);
+                        return None;
+                    }
+                }
+            }
+            self.started = true;
+            self.remaining = self.remaining.map(|n| n - 1);
+
+            Some(&self.current)
+        }
+
+        /// Original positions of the items of the permutation last returned
+        pub fn indices(&self) -> &[usize] {
+            &self.indices
+        }
+
+        /// Jumps straight to the permutation at position `n`, which is the next one returned,
+        /// or finishes if there is no such permutation
+        pub fn seek(&mut self, n: &BigUint) {
+            let total = factorial_big(self.indices.len());
+            if n >= &total {
+                self.finished = true;
+                self.remaining = Some(0);
+                return;
+            }
+            let mut items = self.current.clone();
+            for (&index, &item) in self.indices.iter().zip(self.current.iter()) {
+                items[index] = item;
+            }
+            self.indices = unrank_big(n, self.indices.len());
+            self.current = self.indices.iter().map(|&index| items[index]).collect();
+            self.started = false;
+            self.finished = false;
+            self.remaining = (total - n).to_usize();
+        }
+    }
+
+    impl<'a, T> Iterator for Permutations<'a, T> {
         type Item = Vec<&'a T>;
 
         fn size_hint(&self) -> (usize, Option<usize>) {
-            (0, factorial(self.collection.len()))
+            exact_size_hint(self.remaining)
         }
-        
+
         fn next(&mut self) -> Option<Self::Item> {
-            match self.permutation_number {
-                None => None,
-                Some(n) if n >= factorial(self.collection.len()).expect("collection should be sufficiently small") => None,
-                Some(n) => {
-                    let radix = self.collection.len();
-                    let indices = permutation_indices(n, radix);
-                    let mut references = Vec::new();
-                    for index in indices {
-                        let v = &self.collection[index];
-                        references.push(v);
+            self.advance().map(|permutation| permutation.to_vec())
+        }
+
+        /// Skips ahead via the factoradic rather than stepping through every permutation in between,
+        /// which also makes `skip` jump
+        fn nth(&mut self, n: usize) -> Option<Self::Item> {
+            if self.finished { return None; }
+            let next = if self.started { rank_big(&self.indices) + 1u8 } else { BigUint::zero() };
+            self.seek(&(next + n));
+            self.next()
+        }
+    }
+
+    pub struct KPermutations<'a, T> {
+        items: Vec<&'a T>,
+        /// Positions of the items, of which the first `k` are the current selection
+        /// and the rest are kept in increasing order
+        indices: Vec<usize>,
+        k: usize,
+        started: bool,
+        finished: bool,
+        remaining: Option<usize>
+    }
+
+    impl<'a, T> KPermutations<'a, T> {
+        fn new(items: Vec<&'a T>, k: usize) -> Self {
+            let indices = (0..items.len()).collect();
+            let remaining = permutations_count(items.len(), k);
+            KPermutations { finished: k > items.len(), items, indices, k, started: false, remaining }
+        }
+    }
+
+    impl<'a, T> Iterator for KPermutations<'a, T> {
+        type Item = Vec<&'a T>;
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            exact_size_hint(self.remaining)
+        }
+
+        fn next(&mut self) -> Option<Self::Item> {
+            if self.finished { return None; }
+            if self.started {
+                // Last arrangement of the unselected items, so the next permutation changes the selection
+                self.indices[self.k..].reverse();
+                if !next_permutation(&mut self.indices) {
+                    self.finished = true;
+                    self.remaining = Some(0);
+                    return None;
+                }
+            }
+            self.started = true;
+            self.remaining = self.remaining.map(|n| n - 1);
+
+            Some(self.indices[..self.k].iter().map(|&i| self.items[i]).collect())
+        }
+    }
+
+    pub struct Combinations<'a, T> {
+        items: Vec<&'a T>,
+        /// Strictly increasing positions of the selected items
+        indices: Vec<usize>,
+        started: bool,
+        finished: bool,
+        remaining: Option<usize>
+    }
+
+    impl<'a, T> Combinations<'a, T> {
+        fn new(items: Vec<&'a T>, k: usize) -> Self {
+            let remaining = binomial(items.len(), k);
+            Combinations { finished: k > items.len(), items, indices: (0..k).collect(), started: false, remaining }
+        }
+    }
+
+    impl<'a, T> Iterator for Combinations<'a, T> {
+        type Item = Vec<&'a T>;
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            exact_size_hint(self.remaining)
+        }
+
+        fn next(&mut self) -> Option<Self::Item> {
+            if self.finished { return None; }
+            if self.started {
+                let (n, k) = (self.items.len(), self.indices.len());
+                // Rightmost position that isn't already as far right as it can go
+                match (0..k).rev().find(|&i| self.indices[i] < n - k + i) {
+                    Some(i) => {
+                        self.indices[i] += 1;
+                        for j in i + 1..k {
+                            self.indices[j] = self.indices[j - 1] + 1;
+                        }
+                    },
+                    None => {
+                        self.finished = true;
+                        self.remaining = Some(0);
+                        return None;
                     }
-                    self.permutation_number = n.checked_add(1);
+                }
+            }
+            self.started = true;
+            self.remaining = self.remaining.map(|n| n - 1);
+
+            Some(self.indices.iter().map(|&i| self.items[i]).collect())
+        }
+    }
+
+    pub struct CombinationsWithReplacement<'a, T> {
+        items: Vec<&'a T>,
+        /// Non-decreasing positions of the selected items
+        indices: Vec<usize>,
+        started: bool,
+        finished: bool,
+        remaining: Option<usize>
+    }
+
+    impl<'a, T> CombinationsWithReplacement<'a, T> {
+        fn new(items: Vec<&'a T>, k: usize) -> Self {
+            let n = items.len();
+            let remaining = if n == 0 { Some(if k == 0 { 1 } else { 0 }) } else { binomial(n + k - 1, k) };
+            CombinationsWithReplacement { finished: n == 0 && k > 0, items, indices: vec![0; k], started: false, remaining }
+        }
+    }
 
-                    Some(references)
+    impl<'a, T> Iterator for CombinationsWithReplacement<'a, T> {
+        type Item = Vec<&'a T>;
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            exact_size_hint(self.remaining)
+        }
+
+        fn next(&mut self) -> Option<Self::Item> {
+            if self.finished { return None; }
+            if self.started {
+                match (0..self.indices.len()).rev().find(|&i| self.indices[i] + 1 < self.items.len()) {
+                    Some(i) => {
+                        let next = self.indices[i] + 1;
+                        self.indices[i..].iter_mut().for_each(|index| *index = next);
+                    },
+                    None => {
+                        self.finished = true;
+                        self.remaining = Some(0);
+                        return None;
+                    }
                 }
             }
+            self.started = true;
+            self.remaining = self.remaining.map(|n| n - 1);
+
+            Some(self.indices.iter().map(|&i| self.items[i]).collect())
         }
     }
 
-    impl<'a, T> Permutable<'a ,T> for Vec<T> {
-        type Item = Vec<T>;
-        fn permutations(&'a self) -> Permutations<Self::Item> {
-            if factorial(self.len()) == None {
-                panic!("Vector too large to get permutations of, self.len() == {}", self.len())
+    pub struct Powerset<'a, T> {
+        items: Vec<&'a T>,
+        /// Subsets of the current size
+        combinations: Combinations<'a, T>,
+        remaining: Option<usize>
+    }
+
+    impl<'a, T> Powerset<'a, T> {
+        fn new(items: Vec<&'a T>) -> Self {
+            let remaining = u32::try_from(items.len()).ok().and_then(|n| 1usize.checked_shl(n));
+            Powerset { combinations: Combinations::new(items.clone(), 0), items, remaining }
+        }
+    }
+
+    impl<'a, T> Iterator for Powerset<'a, T> {
+        type Item = Vec<&'a T>;
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            exact_size_hint(self.remaining)
+        }
+
+        fn next(&mut self) -> Option<Self::Item> {
+            loop {
+                if let Some(subset) = self.combinations.next() {
+                    self.remaining = self.remaining.map(|n| n - 1);
+                    return Some(subset);
+                }
+                let size = self.combinations.indices.len();
+                if size == self.items.len() { return None; }
+                self.combinations = Combinations::new(self.items.clone(), size + 1);
             }
-            Permutations::new(self)
         }
     }
 
+    impl<T> Permutable<T> for [T] {
+        fn references(&self) -> Vec<&T> {
+            self.iter().collect()
+        }
+    }
+
+    impl<T, const N: usize> Permutable<T> for [T; N] {
+        fn references(&self) -> Vec<&T> {
+            self.iter().collect()
+        }
+    }
+
+    impl<T> Permutable<T> for Vec<T> {
+        fn references(&self) -> Vec<&T> {
+            self.iter().collect()
+        }
+    }
+
+    impl<T> Permutable<T> for VecDeque<T> {
+        fn references(&self) -> Vec<&T> {
+            self.iter().collect()
+        }
+    }
+
+    /// Splits the positions `0..len!` of the permutations of `len` items into at most `parts` ranges
+    /// of roughly equal size, leaving out empty ones
+    pub fn partition_permutations(len: usize, parts: usize) -> Vec<Range<BigUint>> {
+        assert!(parts > 0, "there should be at least one part");
+        let total = factorial_big(len);
+        let size = (&total + parts - 1u8) / parts;
+        (0..parts)
+            .map(|part| (&size * part).min(total.clone())..(&size * (part + 1)).min(total.clone()))
+            .filter(|range| range.start < range.end)
+            .collect()
+    }
+
+    /// Permutation of `items` with the highest `score`, and that score, scoring them on `threads` threads.
+    /// Each thread gets one range from [`partition_permutations`] and unranks its start to begin there.
+    /// On a tie, the permutation that comes first in lexicographic order wins.
+    ///
+    /// # Panics
+    /// Panics if `threads` is 0, or a thread would have more than `usize::MAX` permutations to score
+    pub fn best_permutation<T, S, F>(items: &[T], threads: usize, score: F) -> (Vec<&T>, S)
+        where
+            T: Sync,
+            S: Ord + Send,
+            F: Fn(&[&T]) -> S + Sync {
+        let score = &score;
+        let results = thread::scope(|scope| {
+            let workers = partition_permutations(items.len(), threads).into_iter()
+                .map(|range| scope.spawn(move || {
+                    let count = (&range.end - &range.start).to_usize().expect("range should have at most usize::MAX permutations");
+                    let mut permutations = items.permutations();
+                    permutations.seek(&range.start);
+                    let mut best: Option<(Vec<usize>, S)> = None;
+                    for _ in 0..count {
+                        let value = score(permutations.advance().expect("range should only cover existing permutations"));
+                        if best.as_ref().map_or(true, |(_, most)| value > *most) {
+                            best = Some((permutations.indices().to_vec(), value));
+                        }
+                    }
+                    best.expect("range should not be empty")
+                }))
+                .collect::<Vec<_>>();
+            workers.into_iter().map(|worker| worker.join().expect("worker thread should not panic")).collect::<Vec<_>>()
+        });
+
+        // Ranges are in order, so only a strictly better score from a later one counts
+        let (indices, value) = results.into_iter()
+            .reduce(|best, result| if result.1 > best.1 { result } else { best })
+            .expect("there should be at least one permutation");
+        (indices.iter().map(|&index| &items[index]).collect(), value)
+    }
+
     #[test]
     fn permutation_simple_test() {
         let v = vec!['a', 'b', 'c'];
@@ -134,10 +595,147 @@ pub mod utils {
         let v = vec!["AA", "BB", "CC", "DD"];
         v.permutations().for_each(|p| println!("{:?}", p));
     }
+
+    #[test]
+    fn next_permutation_test() {
+        let v = vec![3, 0, 2, 1];
+        let mut permutations = v.permutations();
+        assert_eq!((24, Some(24)), permutations.size_hint());
+        for n in 0..24 {
+            let expected = factoradic_as_permutation(&to_factoradic(n, 4));
+            assert_eq!(Some(expected.iter().map(|&i| &v[i]).collect()), permutations.next());
+            assert_eq!(&expected, permutations.indices());
+        }
+        assert_eq!((None, (0, Some(0))), (permutations.next(), permutations.size_hint()));
+
+        let mut items = ['a', 'b', 'b'];
+        let mut seen = vec![items];
+        while next_permutation(&mut items) {
+            seen.push(items);
+        }
+        assert_eq!(vec![['a', 'b', 'b'], ['b', 'a', 'b'], ['b', 'b', 'a']], seen);
+        assert_eq!(['a', 'b', 'b'], items);
+
+        // Too many permutations to count, but still fine to step through
+        let long = (0..25).collect::<Vec<u32>>();
+        let mut permutations = long.permutations();
+        assert_eq!((usize::MAX, None), permutations.size_hint());
+        assert_eq!(Some(&long.iter().collect::<Vec<&u32>>()[..]), permutations.advance());
+        assert_eq!(&[24, 23], &permutations.advance().unwrap()[23..].iter().map(|&&n| n).collect::<Vec<u32>>()[..]);
+
+        let array = [1, 2, 3];
+        let all = array.permutations().collect::<Vec<Vec<&i32>>>();
+        assert_eq!(6, all.len());
+        assert_eq!(all, array[..].permutations().collect::<Vec<Vec<&i32>>>());
+        assert_eq!(all, VecDeque::from(array).permutations().collect::<Vec<Vec<&i32>>>());
+        assert_eq!(all, array.to_vec().permutations().collect::<Vec<Vec<&i32>>>());
+        assert_eq!(1, Vec::<i32>::new().permutations().count());
+    }
+
+    #[test]
+    fn rank_test() {
+        for n in 0..120 {
+            let permutation = unrank(n, 5);
+            assert_eq!(factoradic_as_permutation(&to_factoradic(n, 5)), permutation);
+            assert_eq!(n, rank(&permutation));
+        }
+        assert_eq!((Vec::<usize>::new(), 0), (unrank(0, 0), rank(&[])));
+        assert_eq!(vec![0, 1, 2], unrank(0, 3));
+        assert_eq!(vec![2, 1, 0], unrank(5, 3));
+
+        // 30! is far past a usize
+        let last = (0..30).rev().collect::<Vec<usize>>();
+        assert_eq!(factorial_big(30) - 1u8, rank_big(&last));
+        assert_eq!(last, unrank_big(&(factorial_big(30) - 1u8), 30));
+        let big = BigUint::from(u128::MAX);
+        assert_eq!(big, rank_big(&unrank_big(&big, 40)));
+
+        let v = vec!['a', 'b', 'c', 'd', 'e'];
+        let all = v.permutations().collect::<Vec<Vec<&char>>>();
+        let mut permutations = v.permutations();
+        assert_eq!(Some(all[7].clone()), permutations.nth(7));
+        assert_eq!(Some(all[8].clone()), permutations.next());
+        assert_eq!(Some(all[100].clone()), permutations.nth(91));
+        assert_eq!((19, Some(19)), permutations.size_hint());
+        assert_eq!(all[50..].to_vec(), v.permutations().skip(50).collect::<Vec<Vec<&char>>>());
+        assert_eq!(None, permutations.nth(19));
+        assert_eq!((0, Some(0)), permutations.size_hint());
+
+        let long = (0..35).collect::<Vec<usize>>();
+        let mut permutations = long.permutations();
+        permutations.seek(&big);
+        assert!(permutations.advance().is_some());
+        assert_eq!(unrank_big(&big, 35), permutations.indices());
+        permutations.advance();
+        assert_eq!(big + 1u8, rank_big(permutations.indices()));
+    }
+
+    #[test]
+    fn best_permutation_test() {
+        let ranges = partition_permutations(4, 5);
+        assert_eq!(5, ranges.len());
+        assert_eq!((BigUint::zero(), BigUint::from(24u8)), (ranges[0].start.clone(), ranges[4].end.clone()));
+        assert!(ranges.windows(2).all(|pair| pair[0].end == pair[1].start));
+        assert_eq!(1, partition_permutations(0, 3).len());
+        assert_eq!(6, partition_permutations(3, 10).len());
+
+        // Weighted sum, best with the heaviest items last
+        let items = [5, 3, 8, 1, 9, 2, 7];
+        let weighted = |permutation: &[&i32]| permutation.iter().enumerate().map(|(i, &&item)| i as i32 * item).sum::<i32>();
+        let expected = items.permutations().map(|p| weighted(&p)).max().unwrap();
+        for threads in [1, 3, 8] {
+            let (permutation, value) = best_permutation(&items, threads, weighted);
+            assert_eq!((vec![&1, &2, &3, &5, &7, &8, &9], expected), (permutation, value));
+        }
+        // Every permutation ties, so the first one wins
+        assert_eq!((vec![&'a', &'b', &'c'], 0), best_permutation(&['a', 'b', 'c'], 4, |_| 0));
+    }
+
+    #[test]
+    fn combinations_test() {
+        assert_eq!((Some(10), Some(0), Some(1)), (binomial(5, 2), binomial(2, 5), binomial(0, 0)));
+        assert_eq!(Some(465_428_353_255_261_088), binomial(62, 31));
+        assert_eq!(None, binomial(100, 50));
+        assert_eq!(Some(1), binomial(100, 100));
+
+        let v = vec!['a', 'b', 'c', 'd'];
+        let collect = |iterator: &mut dyn Iterator<Item = Vec<&char>>| iterator.map(|p| p.into_iter().collect::<String>()).collect::<Vec<String>>();
+        let mut pairs = v.combinations(2);
+        assert_eq!((6, Some(6)), pairs.size_hint());
+        assert_eq!(vec!["ab", "ac", "ad", "bc", "bd", "cd"], collect(&mut pairs));
+        assert_eq!((0, Some(0)), pairs.size_hint());
+        assert_eq!(vec![""], collect(&mut v.combinations(0)));
+        assert_eq!(0, v.combinations(5).count());
+
+        let mut ordered = v.permutations_k(2);
+        assert_eq!((12, Some(12)), ordered.size_hint());
+        assert_eq!(vec!["ab", "ac", "ad", "ba", "bc", "bd", "ca", "cb", "cd", "da", "db", "dc"], collect(&mut ordered));
+        assert_eq!((24, 24), (v.permutations_k(3).size_hint().0, v.permutations_k(3).count()));
+        assert_eq!(v.permutations().collect::<Vec<_>>(), v.permutations_k(4).collect::<Vec<_>>());
+        assert_eq!(0, v.permutations_k(5).count());
+
+        let mut repeated = ['x', 'y', 'z'].combinations_with_replacement(2);
+        assert_eq!((6, Some(6)), repeated.size_hint());
+        assert_eq!(vec!["xx", "xy", "xz", "yy", "yz", "zz"], collect(&mut repeated));
+        assert_eq!(vec![""], collect(&mut Vec::<char>::new().combinations_with_replacement(0)));
+        assert_eq!(0, Vec::<char>::new().combinations_with_replacement(1).count());
+
+        let deque = VecDeque::from(vec!['a', 'b', 'c']);
+        let mut subsets = deque.powerset();
+        assert_eq!((8, Some(8)), subsets.size_hint());
+        assert_eq!(vec!["", "a", "b", "c", "ab", "ac", "bc", "abc"], collect(&mut subsets));
+        assert_eq!((0, Some(0)), subsets.size_hint());
+        assert_eq!((usize::MAX, None), (0..64).collect::<Vec<u8>>().powerset().size_hint());
+    }
 }
 
-pub mod sand_stuff {   
-    #[derive(PartialEq)]
+pub mod sand_stuff {
+    use std::str::FromStr;
+    use crate::error::{ParseError, column_of, parse_number};
+    use crate::grid::{Grid, SparseGrid};
+    use crate::point::Point;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
     pub enum Material {
         Air,
         Rock,
@@ -156,6 +754,13 @@ pub mod sand_stuff {
         }
     }
 
+    impl std::fmt::Display for Material {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}", self.as_char())
+        }
+    }
+
+    #[derive(Clone)]
     pub struct DrawInstruction {
         vertices: Vec<(usize, usize)>,
         pub min_x: usize,
@@ -164,28 +769,54 @@ pub mod sand_stuff {
     }
 
     impl DrawInstruction {
-        pub fn new(s: &str) -> Self {
-            let mut vertices = Vec::new();
-            let mut min_x = usize::MAX;
-            let mut max_x = 0;
-            let mut max_y = 0;
-            for pair in s.split(" -> ") {
-                let (left, right) = pair.split_once(',').expect("pair should be formatted 'x,y'");
-                let x = left.parse::<usize>().expect("left side should contain a number");
-                let y = right.parse::<usize>().expect("right side should contain a number");
-                if x < min_x { min_x = x }
-                if x > max_x { max_x = x }
-                if y > max_y { max_y = y }
-                
-                vertices.push((x, y));
-            }
+        /// Path of rock through each of `vertices` in order
+        pub fn from_vertices(vertices: Vec<(usize, usize)>) -> Self {
+            let min_x = vertices.iter().map(|&(x, _)| x).min().unwrap_or(usize::MAX);
+            let max_x = vertices.iter().map(|&(x, _)| x).max().unwrap_or(0);
+            let max_y = vertices.iter().map(|&(_, y)| y).max().unwrap_or(0);
 
             DrawInstruction { vertices, min_x, max_x, max_y }
         }
+
+        /// Straight line of rock from `from` to `to`
+        pub fn line(from: (usize, usize), to: (usize, usize)) -> Self {
+            DrawInstruction::from_vertices(vec![from, to])
+        }
+
+        /// Every position covered by rock along the path, with corners repeated
+        pub fn points(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+            self.vertices.windows(2).flat_map(|slice| {
+                let (this, next) = (slice[0], slice[1]);
+                (this.0.min(next.0)..=this.0.max(next.0))
+                    .flat_map(move |x| (this.1.min(next.1)..=this.1.max(next.1)).map(move |y| (x, y)))
+            })
+        }
+    }
+
+    /// Parses a path such as `498,4 -> 498,6 -> 496,6`
+    impl FromStr for DrawInstruction {
+        type Err = ParseError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let vertices = s.split(" -> ")
+                .map(|pair| {
+                    let (left, right) = pair.split_once(',')
+                        .ok_or_else(|| ParseError::unexpected(1, column_of(s, pair), pair, "a pair formatted 'x,y'"))?;
+                    Ok((parse_number(s, left)?, parse_number(s, right)?))
+                })
+                .collect::<Result<Vec<(usize, usize)>, ParseError>>()?;
+
+            Ok(DrawInstruction::from_vertices(vertices))
+        }
+    }
+
+    /// Position in the canvas' map of the normalized coordinate `(x, y)`
+    fn at(x: usize, y: usize) -> Point<i32> {
+        Point::new(x as i32, y as i32)
     }
 
     pub struct Canvas {
-        pub map: Vec<Vec<Material>>,
+        pub map: Grid<Material>,
         pub source_coord: (usize, usize),
         pub active_sand: Option<(usize, usize)>,
         pub min_x: usize,
@@ -201,12 +832,7 @@ pub mod sand_stuff {
 
             let height = max_y - min_y;
             let width = max_x - min_x;
-            let map = (0..height)
-                .map(|_y| {
-                    (0..width).map(|_x| {
-                        Material::Air
-                    }).collect::<Vec<Material>>()
-                }).collect::<Vec<Vec<Material>>>();
+            let map = Grid::new(width, height, Material::Air);
 
             let mut canvas = Canvas { map, source_coord, active_sand: None, min_x, min_y };
 
@@ -215,7 +841,7 @@ pub mod sand_stuff {
             }
 
             let (source_x, source_y) = canvas.map_coord(source_coord.0, source_coord.1);
-            canvas.map[source_y][source_x] = Material::SandSource;
+            canvas.map[at(source_x, source_y)] = Material::SandSource;
 
             canvas
         }
@@ -227,14 +853,9 @@ pub mod sand_stuff {
         }
 
         pub fn draw_rocks(&mut self, instruction: DrawInstruction) {
-            for slice in instruction.vertices.windows(2) {
-                let (this, next) = (slice[0], slice[1]);
-                for x in this.0.min(next.0)..this.0.max(next.0) + 1 {
-                    for y in this.1.min(next.1)..this.1.max(next.1) + 1 {
-                        let (x, y) = self.map_coord(x, y);
-                        self.map[y][x] = Material::Rock;
-                    }
-                }
+            for (x, y) in instruction.points() {
+                let (x, y) = self.map_coord(x, y);
+                self.map[at(x, y)] = Material::Rock;
             }
         }
 
@@ -250,27 +871,19 @@ pub mod sand_stuff {
 
         /// Checks tile to bottom right, bottom left and directly below.
         fn attempt_move_down(&self, x: usize, y: usize) -> Option<(usize, usize)> {
-            match self.map.get(y + 1) {
-                None => { None },
-                Some(row_below) => {
-                    match row_below.get(x) {
-                        None => unreachable!(),
-                        Some(Material::Air) => Some((x, y + 1)),
-                        // Check diagonals
-                        Some(_) => {
-                            let down_left = x.checked_sub(1).and_then(|x| row_below.get(x));
-                            let down_right = row_below.get(x + 1);
-                            match (down_left, down_right) {
-                                (Some(material), Some(_) | None) if material == &Material::Air => {
-                                    Some((x - 1, y + 1))
-                                }
-                                (Some(_) | None, Some(material)) if material == &Material::Air => {
-                                    Some((x + 1, y + 1))
-                                },
-                                (Some(_), Some(_)) => Some((x, y)),
-                                (Some(_), None) | (None, _) => None
-                            }
-                        }
+            let below = at(x, y + 1);
+            match self.map.get(below) {
+                None => None,
+                Some(Material::Air) => Some((x, y + 1)),
+                // Check diagonals
+                Some(_) => {
+                    let down_left = self.map.get(below.add_tuple((-1, 0)));
+                    let down_right = self.map.get(below.add_tuple((1, 0)));
+                    match (down_left, down_right) {
+                        (Some(Material::Air), _) => Some((x - 1, y + 1)),
+                        (_, Some(Material::Air)) => Some((x + 1, y + 1)),
+                        (Some(_), Some(_)) => Some((x, y)),
+                        (Some(_), None) | (None, _) => None
                     }
                 }
             }
@@ -286,11 +899,10 @@ pub mod sand_stuff {
             if self.active_sand.is_none() { panic!("Attempt to update canvas when no active sand is present") }
 
             let (x, y) = self.active_sand.unwrap();
-            if self.map[y][x] == Material::Sand { self.map[y][x] = Material::Air; }
+            if self.map[at(x, y)] == Material::Sand { self.map[at(x, y)] = Material::Air; }
             let new_position: Option<(usize, usize)> = self.attempt_move_down(x, y);
-            if new_position.is_some() {
-                let (new_x, new_y) = new_position.unwrap();
-                self.map[new_y][new_x] = Material::Sand;
+            if let Some((new_x, new_y)) = new_position {
+                self.map[at(new_x, new_y)] = Material::Sand;
                 if x == new_x && y == new_y {
                     self.active_sand = None;
                 } else {
@@ -311,9 +923,9 @@ pub mod sand_stuff {
         /// that can fall. If there is, sets `self.active_sand` to its position and returns `true`.
         /// Otherwise, returns `false`.
         pub fn reactivate(&mut self) -> bool {
-            for (y, row) in self.map.iter().enumerate().rev() {
-                for x in 0..row.len() {
-                    if row[x] == Material::Sand {
+            for (y, row) in self.map.rows().enumerate().rev() {
+                for (x, material) in row.iter().enumerate() {
+                    if material == &Material::Sand {
                         let new_pos = self.attempt_move_down(x, y);
                         if new_pos.is_some() && new_pos.unwrap() == (x, y) {
                             continue;
@@ -328,16 +940,14 @@ pub mod sand_stuff {
         }
 
         pub fn count_material(&self, material: Material) -> usize {
-            self.map.iter()
-            .map(|row| row.iter().filter(|m| m == &&material).count())
-            .sum()
+            self.map.values().filter(|&&m| m == material).count()
         }
 
         /// Unfortunately trait Display has a limit for the amount of data it can output,
         /// necessitating that you just print it out manually for the full puzzle output
         /// (especially true for part 2)
         pub fn display(&self) {
-            for row in self.map.iter() {
+            for row in self.map.rows() {
                 println!("{}", row.iter().map(Material::as_char).collect::<String>())
             }
         }
@@ -345,12 +955,71 @@ pub mod sand_stuff {
 
     impl std::fmt::Display for Canvas {
         fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            let s = self.map.iter()
-                .map(|row| {
-                    row.iter().map(Material::as_char).collect::<String>() + "\n"
-                })
-                .collect::<String>();
-            write!(f, "{}", s)
+            write!(f, "{}", self.map)
+        }
+    }
+
+    /// Unbounded alternative to `Canvas` that drops each particle of sand all the way in one go,
+    /// so the cave does not need to be sized up front
+    pub struct SandCave {
+        pub tiles: SparseGrid<Material>,
+        pub source: Point<i64>,
+        /// Lowest point of rock, below which sand falls forever if there is no floor
+        lowest_rock: i64,
+        /// Height of an infinitely wide floor of rock, if there is one
+        floor: Option<i64>
+    }
+
+    impl SandCave {
+        pub fn new(instructions: &[DrawInstruction], source: (usize, usize)) -> Self {
+            let mut tiles = SparseGrid::new();
+            for (x, y) in instructions.iter().flat_map(DrawInstruction::points) {
+                tiles.insert(Point::new(x as i64, y as i64), Material::Rock);
+            }
+            let source = Point::new(source.0 as i64, source.1 as i64);
+            tiles.insert(source, Material::SandSource);
+            let lowest_rock = instructions.iter().map(|i| i.max_y as i64).max().unwrap_or(0);
+
+            SandCave { tiles, source, lowest_rock, floor: None }
+        }
+
+        /// Adds a floor `distance` below the lowest rock
+        pub fn with_floor(mut self, distance: i64) -> Self {
+            self.floor = Some(self.lowest_rock + distance);
+            self
+        }
+
+        fn is_free(&self, point: Point<i64>) -> bool {
+            self.floor != Some(point.y) && !matches!(self.tiles.get(point), Some(Material::Rock | Material::Sand))
+        }
+
+        /// Drops a particle of sand from the source, returning where it comes to rest.
+        /// Returns `None` if it falls into the abyss or the source is already plugged.
+        pub fn drop_sand(&mut self) -> Option<Point<i64>> {
+            if !self.is_free(self.source) { return None; }
+
+            let mut sand = self.source;
+            loop {
+                if self.floor.is_none() && sand.y > self.lowest_rock { return None; }
+                match [(0, 1), (-1, 1), (1, 1)].iter().map(|&step| sand.add_tuple(step)).find(|&p| self.is_free(p)) {
+                    Some(next) => sand = next,
+                    None => break
+                }
+            }
+            self.tiles.insert(sand, Material::Sand);
+
+            Some(sand)
+        }
+
+        pub fn count_material(&self, material: Material) -> usize {
+            self.tiles.values().filter(|&&m| m == material).count()
+        }
+    }
+
+    /// Only covers the region containing rock and sand, so any floor is not shown
+    impl std::fmt::Display for SandCave {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}", self.tiles.render(Material::Air.as_char()))
         }
     }
 }
@@ -362,7 +1031,7 @@ pub mod point {
     use num::{abs, range};
 
     /// 2-dimensional points object with signed integers
-    #[derive(Debug, Hash, Clone, Copy, Eq)]
+    #[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
     pub struct Point<N> where N: Integer + Signed + Copy + Hash + ToPrimitive {
         pub x: N,
         pub y: N
@@ -370,7 +1039,7 @@ pub mod point {
     
     impl<N> Point<N> where N: Integer + Signed + Copy + Hash + ToPrimitive {
         pub fn new(x: N, y: N) -> Self {
-            Point { x: x, y: y }
+            Point { x, y }
         }
 
         pub fn zero() -> Self {
@@ -412,12 +1081,6 @@ pub mod point {
         }
     }
 
-    impl<N> PartialEq for Point<N> where N: Integer + Signed + Copy + Hash + ToPrimitive {
-        fn eq(&self, other: &Self) -> bool {
-            self.x == other.x && self.y == other.y
-        }
-    }
-
     impl<N> PartialEq<(N, N)> for Point<N> where N: Integer + Signed + Copy + Hash + ToPrimitive {
         fn eq(&self, other: &(N, N)) -> bool {
             self.x == other.0 && self.y == other.1
@@ -0,0 +1,105 @@
+use crate::error::{ParseError, column_of};
+
+/// Splits `line` up by `pattern`, where each `{}` is a field and the text around them has to match exactly,
+/// returning the text of every field as a slice of `line` so that callers can still point at it.
+/// A field runs up to the first occurrence of the text following it, or to the end of the line if it is last.
+///
+/// # Panics
+/// Panics if two fields are next to each other in `pattern`, as there would be no telling where one ends
+pub fn fields<'a>(line: &'a str, pattern: &str) -> Result<Vec<&'a str>, ParseError> {
+    let literals = pattern.split("{}").collect::<Vec<&str>>();
+    assert!(literals.iter().skip(1).rev().skip(1).all(|literal| !literal.is_empty()), "fields in '{}' should be separated by some text", pattern);
+
+    let mut rest = expect(line, line, literals[0])?;
+    let mut fields = Vec::new();
+    for &literal in &literals[1..] {
+        let end = match literal {
+            "" => rest.len(),
+            _ => match rest.find(literal) {
+                Some(end) => end,
+                // Assume the field ends at the first whitespace or where the text following it should have started,
+                // to say what was there instead
+                None => {
+                    let first = literal.chars().next().unwrap_or(' ');
+                    let guess = rest.find(|c: char| c == first || c.is_whitespace()).unwrap_or(rest.len());
+                    return Err(mismatch(line, &rest[guess..], literal));
+                }
+            }
+        };
+        fields.push(&rest[..end]);
+        rest = &rest[end + literal.len()..];
+    }
+    if !rest.is_empty() {
+        return Err(ParseError::unexpected(1, column_of(line, rest), rest, "the end of the line"));
+    }
+
+    Ok(fields)
+}
+
+/// `rest`, a slice of `line`, without the `literal` it should start with
+fn expect<'a>(line: &str, rest: &'a str, literal: &str) -> Result<&'a str, ParseError> {
+    rest.strip_prefix(literal).ok_or_else(|| mismatch(line, rest, literal))
+}
+
+/// Error for `rest`, a slice of `line`, not starting with `literal`, pointing at the word that is there instead
+fn mismatch(line: &str, rest: &str, literal: &str) -> ParseError {
+    let expected = match literal.trim() {
+        "" => format!("'{}'", literal),
+        trimmed => format!("'{}'", trimmed)
+    };
+    let rest = rest.trim_start();
+    match rest.split_whitespace().next() {
+        Some(found) => ParseError::unexpected(1, column_of(line, found), found, &expected),
+        None => ParseError::missing(1, line.chars().count() + 1, &expected)
+    }
+}
+
+/// Parses `line` by a `pattern` of [`fields`] into a tuple of the given types, one for each `{}`,
+/// reporting the column of the first field that doesn't parse.
+///
+/// # Examples
+/// ```
+/// use advent_of_code2022::scan;
+/// let sensor = scan!("Sensor at x=2, y=-18", "Sensor at x={}, y={}", i32, i32);
+/// assert_eq!(Ok((2, -18)), sensor);
+/// ```
+///
+/// # Panics
+/// Panics if the number of types doesn't match the number of fields in `pattern`
+#[macro_export]
+macro_rules! scan {
+    ($line:expr, $pattern:expr, $($ty:ty),+ $(,)?) => {{
+        let line: &str = $line;
+        'scan: {
+            let fields = match $crate::scan::fields(line, $pattern) {
+                Ok(fields) => fields,
+                Err(e) => break 'scan Err(e)
+            };
+            assert_eq!([$(stringify!($ty)),+].len(), fields.len(), "pattern should have a field for every type");
+            let mut fields = fields.into_iter();
+            Ok(($(
+                match $crate::error::parse_number::<$ty>(line, fields.next().unwrap()) {
+                    Ok(value) => value,
+                    Err(e) => break 'scan Err(e)
+                },
+            )+))
+        }
+    }};
+}
+
+#[test]
+fn scan_test() {
+    let line = "move 3 from 1 to 2";
+    assert_eq!(Ok(vec!["3", "1", "2"]), fields(line, "move {} from {} to {}"));
+    assert_eq!(Ok((3, 1, 2)), scan!(line, "move {} from {} to {}", usize, usize, usize));
+    assert_eq!(Ok((String::from("3 from 1"), 2)), scan!(line, "move {} to {}", String, u8));
+    assert_eq!(Ok(vec![line]), fields(line, "{}"));
+    assert_eq!(Ok(Vec::<&str>::new()), fields(line, line));
+
+    assert_eq!(Err(ParseError::unexpected(1, 8, "form", "'from'")), fields("move 3 form 1 to 2", "move {} from {} to {}"));
+    assert_eq!(Err(ParseError::unexpected(1, 1, "mvoe", "'move'")), fields("mvoe 3 from 1 to 2", "move {} from {} to {}"));
+    assert_eq!(Err(ParseError::missing(1, 14, "'to'")), fields("move 3 from 1", "move {} from {} to {}"));
+    assert_eq!(Err(ParseError::unexpected(1, 10, " and more", "the end of the line")), fields("Monkey 0: and more", "Monkey {}:"));
+    assert_eq!(Err(ParseError::invalid_number(1, 6, "x")), scan!("move x from 1 to 2", "move {} from {} to {}", usize, usize, usize));
+    assert_eq!(Err(ParseError::invalid_number(1, 18, "-2")), scan!("move 3 from 1 to -2", "move {} from {} to {}", usize, usize, usize));
+}
@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+
+/// Most sites a schedule can have, as opened sets are bitmasks
+pub const MAX_SITES: usize = u32::BITS as usize;
+
+/// Scheduling problem where an actor moves between sites, doing each site's task at most once
+/// for a value that depends on the time left once it is done, such as opening day 16's valves
+pub trait Schedule {
+    /// Number of sites, at most [`MAX_SITES`]. Bit `i` of an opened set refers to site `i`.
+    fn sites(&self) -> usize;
+
+    /// Time taken to get from `from`, or the starting position if `None`, to site `to` and do its task.
+    /// `None` if `to` cannot be reached.
+    fn cost(&self, from: Option<usize>, to: usize) -> Option<u32>;
+
+    /// Value gained by finishing the task at `site` with `time_left` remaining
+    fn value(&self, site: usize, time_left: u32) -> u64;
+}
+
+/// Where the actor is, and which sites have been done
+type State = (Option<usize>, u32);
+
+/// Most value that can be gained in `time` by doing exactly the sites in each opened set, for every set
+/// that can be done in time, including the empty set.
+///
+/// Each `(position, time left, opened set)` state is only expanded once, with the most value any route
+/// to it has gained, going from the most time left to the least.
+pub fn best_per_set<S: Schedule>(problem: &S, time: u32) -> HashMap<u32, u64> {
+    assert!(problem.sites() <= MAX_SITES, "there should be at most {} sites", MAX_SITES);
+    // Memoised states, indexed by the time left
+    let mut layers: Vec<HashMap<State, u64>> = vec![HashMap::new(); time as usize + 1];
+    layers[time as usize].insert((None, 0), 0);
+    let mut best = HashMap::new();
+
+    for time_left in (0..=time).rev() {
+        for ((position, opened), gained) in std::mem::take(&mut layers[time_left as usize]) {
+            let entry = best.entry(opened).or_insert(0);
+            *entry = gained.max(*entry);
+            for site in (0..problem.sites()).filter(|&site| opened & (1 << site) == 0) {
+                let remaining = match problem.cost(position, site) {
+                    Some(cost) if cost > 0 && cost < time_left => time_left - cost,
+                    _ => continue
+                };
+                let next = layers[remaining as usize].entry((Some(site), opened | (1 << site))).or_insert(0);
+                *next = (gained + problem.value(site, remaining)).max(*next);
+            }
+        }
+    }
+
+    best
+}
+
+/// Most value that can be gained in `time` by a single actor
+pub fn best<S: Schedule>(problem: &S, time: u32) -> u64 {
+    best_per_set(problem, time).into_values().max().unwrap_or(0)
+}
+
+/// Most value two actors can gain together from the results of [`best_per_set`], as long as
+/// they never do the same site
+pub fn best_disjoint_pair(best: &HashMap<u32, u64>) -> u64 {
+    let mut sets = best.iter().map(|(&opened, &gained)| (opened, gained)).collect::<Vec<(u32, u64)>>();
+    sets.sort_by_key(|&(_, gained)| std::cmp::Reverse(gained));
+    let mut most = 0;
+    for (index, &(mine, gained)) in sets.iter().enumerate() {
+        // Sorted, so nothing after can beat the best found so far
+        if gained * 2 <= most { break; }
+        for &(theirs, other_gained) in &sets[index..] {
+            if gained + other_gained <= most { break; }
+            if mine & theirs == 0 {
+                most = gained + other_gained;
+            }
+        }
+    }
+
+    most
+}
+
+#[test]
+fn schedule_brute_force_test() {
+    use crate::days::day16;
+    use crate::utils::Permutable;
+
+    let network = day16::parse(day16::EXAMPLE).unwrap();
+    let sites = (0..network.sites()).collect::<Vec<usize>>();
+    for time in [10, 26, 30] {
+        // Every order of every set is the prefix of some permutation
+        let mut expected = HashMap::new();
+        for order in sites.permutations() {
+            let (mut position, mut time_left, mut opened, mut gained) = (None, time, 0u32, 0);
+            expected.entry(0).or_insert(0);
+            for &site in order {
+                match network.cost(position, site) {
+                    Some(cost) if cost < time_left => time_left -= cost,
+                    _ => break
+                }
+                position = Some(site);
+                opened |= 1 << site;
+                gained += network.value(site, time_left);
+                let entry = expected.entry(opened).or_insert(0);
+                *entry = gained.max(*entry);
+            }
+        }
+
+        let found = best_per_set(&network, time);
+        assert_eq!(expected, found);
+        assert_eq!(expected.values().max(), Some(&best(&network, time)));
+    }
+    assert_eq!(1651, best(&network, 30));
+    assert_eq!(1707, best_disjoint_pair(&best_per_set(&network, 26)));
+}
@@ -0,0 +1,198 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+use crate::bench::as_millis;
+use crate::error::ParseError;
+use crate::json::Json;
+
+/// Answer to a single part of a puzzle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i64),
+    Text(String),
+    /// Rows of an image, such as the letters drawn by day 10's CRT
+    Grid(Vec<String>)
+}
+
+impl Answer {
+    /// Splits a multi-line image into a [`Answer::Grid`]
+    pub fn grid(image: &str) -> Self {
+        Answer::Grid(image.lines().map(String::from).collect())
+    }
+
+    /// Name of the answer's type as used in JSON records
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Answer::Integer(_) => "integer",
+            Answer::Text(_) => "string",
+            Answer::Grid(_) => "grid"
+        }
+    }
+
+    /// Integers become numbers, text becomes a string and grids become an array of rows
+    pub fn to_json(&self) -> Json {
+        match self {
+            Answer::Integer(n) => Json::from(*n),
+            Answer::Text(s) => Json::from(s.as_str()),
+            Answer::Grid(rows) => Json::from(rows.clone())
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Grid(rows) => write!(f, "{}", rows.join("\n"))
+        }
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        Answer::Integer(value as i64)
+    }
+}
+
+/// Values too large for an [`Answer::Integer`] are kept exactly as text
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        i64::try_from(value).map_or_else(|_| Answer::Text(value.to_string()), Answer::Integer)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Integer(value)
+    }
+}
+
+/// Values too large for an [`Answer::Integer`] are kept exactly as text
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        i64::try_from(value).map_or_else(|_| Answer::Text(value.to_string()), Answer::Integer)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2
+        }
+    }
+
+    pub fn from_number(n: u8) -> Option<Self> {
+        match n {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None
+        }
+    }
+}
+
+/// Every day's solution is registered through this trait so that it can be run by the `aoc` binary.
+/// The input is parsed once and then shared between both parts.
+pub trait Solver {
+    const DAY: u8;
+    type Input;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError>;
+    fn part_1(&self, input: &Self::Input) -> Answer;
+    fn part_2(&self, input: &Self::Input) -> Answer;
+}
+
+/// Result of running one part of a day
+#[derive(Debug)]
+pub struct PartReport {
+    pub part: Part,
+    pub answer: Answer,
+    pub elapsed: Duration
+}
+
+/// Result of running a day; `parse_time` is measured separately from the parts
+#[derive(Debug)]
+pub struct Report {
+    pub day: u8,
+    pub parse_time: Duration,
+    pub parts: Vec<PartReport>
+}
+
+impl Report {
+    /// One `{"day":1,"part":1,"answer":...,"type":...,"elapsed_ms":...}` record per part that was run
+    pub fn to_json_records(&self) -> Vec<Json> {
+        self.parts.iter()
+            .map(|part| Json::object([
+                ("day", Json::from(self.day)),
+                ("part", Json::from(part.part.number())),
+                ("answer", part.answer.to_json()),
+                ("type", Json::from(part.answer.kind())),
+                ("elapsed_ms", Json::from(as_millis(part.elapsed)))
+            ]))
+            .collect()
+    }
+}
+
+/// Object-safe version of [`Solver`] so that solvers with different inputs can be stored together,
+/// implemented for every `Solver`
+pub trait Solution {
+    fn day(&self) -> u8;
+    fn run(&self, input: &str, parts: &[Part]) -> Result<Report, ParseError>;
+}
+
+impl<S: Solver> Solution for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn run(&self, input: &str, parts: &[Part]) -> Result<Report, ParseError> {
+        let start = Instant::now();
+        let parsed = self.parse(input)?;
+        let parse_time = start.elapsed();
+
+        let parts = parts.iter()
+            .map(|&part| {
+                let start = Instant::now();
+                let answer = match part {
+                    Part::One => self.part_1(&parsed),
+                    Part::Two => self.part_2(&parsed)
+                };
+                PartReport { part, answer, elapsed: start.elapsed() }
+            })
+            .collect();
+
+        Ok(Report { day: S::DAY, parse_time, parts })
+    }
+}
+
+#[test]
+fn answer_json_test() {
+    let report = Report {
+        day: 10,
+        parse_time: Duration::from_millis(1),
+        parts: vec![
+            PartReport { part: Part::One, answer: 13140.into(), elapsed: Duration::from_micros(1500) },
+            PartReport { part: Part::Two, answer: Answer::grid("##..\n#..#\n"), elapsed: Duration::from_millis(2) }
+        ]
+    };
+    let records = report.to_json_records().iter().map(Json::to_string).collect::<Vec<String>>();
+    assert_eq!(r#"{"day":10,"part":1,"answer":13140,"type":"integer","elapsed_ms":1.5}"#, records[0]);
+    assert_eq!(r###"{"day":10,"part":2,"answer":["##..","#..#"],"type":"grid","elapsed_ms":2}"###, records[1]);
+    assert_eq!("CMZ", Answer::from("CMZ".to_string()).to_json().to_string().trim_matches('"'));
+    assert_eq!(Answer::Integer(i64::MAX), Answer::from(i64::MAX as u64));
+    assert_eq!(Answer::Text("18446744073709551615".to_string()), Answer::from(u64::MAX));
+}